@@ -10,6 +10,7 @@
 
 use proc_macro2::TokenStream;
 use quote::{quote, format_ident};
+use syn::ext::IdentExt;
 use syn::{Data, DeriveInput, Fields, GenericArgument, PathArguments, Type};
 use heck::ToSnakeCase;
 
@@ -25,18 +26,87 @@ use crate::types::rust_type_to_sql;
 
 /// Extracts the inner type `T` from `Option<T>`.
 fn get_inner_type(ty: &Type) -> Option<&Type> {
-    if let Type::Path(type_path) = ty {
-        if let Some(segment) = type_path.path.segments.last() {
-            if segment.ident == "Option" {
-                if let PathArguments::AngleBracketed(args) = &segment.arguments {
-                    if let Some(GenericArgument::Type(inner_ty)) = args.args.first() {
-                        return Some(inner_ty);
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == "Option"
+        && let PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(GenericArgument::Type(inner_ty)) = args.args.first()
+    {
+        return Some(inner_ty);
+    }
+    None
+}
+
+/// Returns the SQL column name for a field, dropping the `r#` prefix of raw identifiers.
+///
+/// Every generated name (columns, keys, indexes, `to_map` entries and row lookups) goes
+/// through this so they all agree with `ColumnInfo::name`.
+fn column_name(ident: &syn::Ident) -> String {
+    ident.unraw().to_string()
+}
+
+/// A struct-level `#[orm(index(...))]` declaration.
+struct IndexDecl {
+    name: Option<String>,
+    columns: Vec<(syn::Ident, bool)>,
+    unique: bool,
+    predicate: Option<String>,
+    method: Option<String>,
+}
+
+/// Parses the contents of `index(...)`.
+///
+/// Accepts `name = "..."`, `columns(a, b desc)`, `unique`, `where = "..."` and `using = "..."`.
+fn parse_index(meta: &syn::meta::ParseNestedMeta) -> syn::Result<IndexDecl> {
+    let mut decl = IndexDecl { name: None, columns: Vec::new(), unique: false, predicate: None, method: None };
+
+    meta.parse_nested_meta(|item| {
+        if item.path.is_ident("name") {
+            let value: syn::LitStr = item.value()?.parse()?;
+            decl.name = Some(value.value());
+        } else if item.path.is_ident("columns") {
+            let content;
+            syn::parenthesized!(content in item.input);
+
+            while !content.is_empty() {
+                let column: syn::Ident = content.parse()?;
+
+                // Optional sort direction after the column name
+                let mut descending = false;
+                if content.peek(syn::Ident) {
+                    let direction: syn::Ident = content.parse()?;
+                    match direction.to_string().to_lowercase().as_str() {
+                        "desc" => descending = true,
+                        "asc" => {}
+                        _ => return Err(syn::Error::new(direction.span(), "expected `asc` or `desc`")),
                     }
                 }
+
+                decl.columns.push((column, descending));
+
+                if !content.is_empty() {
+                    content.parse::<syn::Token![,]>()?;
+                }
             }
+        } else if item.path.is_ident("unique") {
+            decl.unique = true;
+        } else if item.path.is_ident("where") {
+            let value: syn::LitStr = item.value()?.parse()?;
+            decl.predicate = Some(value.value());
+        } else if item.path.is_ident("using") {
+            let value: syn::LitStr = item.value()?.parse()?;
+            decl.method = Some(value.value());
+        } else {
+            return Err(item.error("expected `name`, `columns`, `unique`, `where` or `using`"));
         }
+        Ok(())
+    })?;
+
+    if decl.columns.is_empty() {
+        return Err(meta.error("index requires at least one column: `columns(a, b)`"));
     }
-    None
+
+    Ok(decl)
 }
 
 // ============================================================================
@@ -62,19 +132,62 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
         panic!("Model must be a struct")
     };
 
+    // ========================================================================
+    // Parse Struct-Level Attributes
+    // ========================================================================
+
+    // Composite primary key declared as `#[orm(primary_key(a, b))]`
+    let mut composite_pk: Vec<syn::Ident> = Vec::new();
+
+    // Indexes declared as `#[orm(index(...))]`
+    let mut index_decls: Vec<IndexDecl> = Vec::new();
+
+    for attr in &ast.attrs {
+        if attr.path().is_ident("orm") {
+            let parsed = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("primary_key") {
+                    meta.parse_nested_meta(|col| {
+                        let ident = col.path.require_ident()?;
+                        composite_pk.push(ident.clone());
+                        Ok(())
+                    })
+                } else if meta.path.is_ident("index") {
+                    index_decls.push(parse_index(&meta)?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported struct-level orm attribute"))
+                }
+            });
+
+            if let Err(e) = parsed {
+                return e.to_compile_error();
+            }
+        }
+    }
+
+    // Every composite key and index column must name an existing field
+    let index_columns = index_decls.iter().flat_map(|d| d.columns.iter().map(|(c, _)| c));
+    for pk in composite_pk.iter().chain(index_columns) {
+        if !fields.named.iter().any(|f| f.ident.as_ref() == Some(pk)) {
+            return syn::Error::new(pk.span(), format!("column `{}` is not a field of this struct", pk))
+                .to_compile_error();
+        }
+    }
+
     // ========================================================================
     // Generate Column Definitions
     // ========================================================================
 
-    let column_defs = fields.named.iter().map(|f| {
+    let column_defs = fields.named.iter().map(|f| -> syn::Result<TokenStream> {
         let field_name = &f.ident;
+        let name_str = column_name(field_name.as_ref().unwrap());
         let field_type = &f.ty;
 
         // Map Rust type to SQL type and check if nullable
         let (mut sql_type, is_nullable) = rust_type_to_sql(field_type);
 
         // Initialize attribute flags with defaults
-        let mut is_primary_key = false;
+        let mut is_primary_key = composite_pk.iter().any(|pk| field_name.as_ref() == Some(pk));
         let mut size = None;
         let mut create_time = false;
         let mut update_time = false;
@@ -84,6 +197,16 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
         let mut soft_delete = false;
         let mut foreign_table_tokens = quote! { None };
         let mut foreign_key_tokens = quote! { None };
+        let mut default_tokens = quote! { None };
+        let mut check_tokens = quote! { None };
+        let mut generated_tokens = quote! { None };
+        let mut is_generated = false;
+        let mut stored = false;
+        let mut auto_increment = false;
+        let mut postgres_type_tokens = quote! { None };
+        let mut mysql_type_tokens = quote! { None };
+        let mut sqlite_type_tokens = quote! { None };
+        let mut sensitive = false;
 
         // --------------------------------------------------------------------
         // Parse ORM Attributes
@@ -130,20 +253,74 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
                     if meta.path.is_ident("soft_delete") {
                         soft_delete = true;
                     }
+                    if meta.path.is_ident("default") {
+                        // String literals are raw SQL; other literals are rendered as SQL values
+                        let sql = match meta.value()?.parse::<syn::Lit>()? {
+                            syn::Lit::Str(v) => v.value(),
+                            syn::Lit::Int(v) => v.base10_digits().to_string(),
+                            syn::Lit::Float(v) => v.base10_digits().to_string(),
+                            syn::Lit::Bool(v) => if v.value { "TRUE" } else { "FALSE" }.to_string(),
+                            _ => return Err(meta.error("default must be a string, number or boolean literal")),
+                        };
+                        default_tokens = quote! { Some(#sql) };
+                    }
+                    if meta.path.is_ident("check") {
+                        let value: syn::LitStr = meta.value()?.parse()?;
+                        let expr = value.value();
+                        check_tokens = quote! { Some(#expr) };
+                    }
+                    if meta.path.is_ident("generated") {
+                        let value: syn::LitStr = meta.value()?.parse()?;
+                        let expr = value.value();
+                        generated_tokens = quote! { Some(#expr) };
+                        is_generated = true;
+                    }
+                    if meta.path.is_ident("stored") {
+                        stored = true;
+                    }
+                    if meta.path.is_ident("auto_increment") {
+                        auto_increment = true;
+                    }
+                    if meta.path.is_ident("sensitive") {
+                        sensitive = true;
+                    }
+                    if meta.path.is_ident("sql_type") {
+                        meta.parse_nested_meta(|driver| {
+                            let value: syn::LitStr = driver.value()?.parse()?;
+                            let ty = value.value();
+                            if driver.path.is_ident("postgres") {
+                                postgres_type_tokens = quote! { Some(#ty) };
+                            } else if driver.path.is_ident("mysql") {
+                                mysql_type_tokens = quote! { Some(#ty) };
+                            } else if driver.path.is_ident("sqlite") {
+                                sqlite_type_tokens = quote! { Some(#ty) };
+                            } else {
+                                return Err(driver.error("expected `postgres`, `mysql` or `sqlite`"));
+                            }
+                            Ok(())
+                        })?;
+                    }
                     Ok(())
-                })
-                .expect("Failed to parse orm attributes");
+                })?;
             }
         }
 
+        if stored && !is_generated {
+            return Err(syn::Error::new_spanned(f, "`stored` requires `generated = \"...\"` on the same field"));
+        }
+
+        if auto_increment && !matches!(sql_type.as_str(), "INTEGER" | "BIGINT" | "SMALLINT") {
+            return Err(syn::Error::new_spanned(f, "`auto_increment` requires an integer field"));
+        }
+
         if let Some(s) = size
             && sql_type == "TEXT" {
                 sql_type = format!("VARCHAR({})", s);
             }
 
-        quote! {
+        Ok(quote! {
             bottle_orm::ColumnInfo {
-                 name: stringify!(#field_name),
+                 name: #name_str,
                  sql_type: #sql_type,
                  is_primary_key: #is_primary_key,
                  is_nullable: #is_nullable,
@@ -155,15 +332,95 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
                  foreign_key: #foreign_key_tokens,
                  omit: #omit,
                  soft_delete: #soft_delete,
+                 default: #default_tokens,
+                 check: #check_tokens,
+                 generated: #generated_tokens,
+                 stored: #stored,
+                 auto_increment: #auto_increment,
+                 postgres_type: #postgres_type_tokens,
+                 mysql_type: #mysql_type_tokens,
+                 sqlite_type: #sqlite_type_tokens,
+                 sensitive: #sensitive,
+            }
+        })
+    });
+    let column_defs = match column_defs.collect::<syn::Result<Vec<_>>>() {
+        Ok(defs) => defs,
+        Err(e) => return e.to_compile_error(),
+    };
+
+    // ========================================================================
+    // Generate Primary Keys Override
+    // ========================================================================
+
+    // Keep the column order given in the struct-level attribute; otherwise the
+    // trait's default (declaration order) is used
+    let primary_keys_fn = if composite_pk.is_empty() {
+        quote! {}
+    } else {
+        let pk_names = composite_pk.iter().map(column_name);
+        quote! {
+            fn primary_keys() -> Vec<&'static str> {
+                vec![#(#pk_names),*]
+            }
+        }
+    };
+
+    // ========================================================================
+    // Generate Indexes Override
+    // ========================================================================
+
+    let table_snake = struct_name.to_string().to_snake_case();
+    let index_defs = index_decls.iter().map(|decl| {
+        let column_names: Vec<String> =
+            decl.columns.iter().map(|(c, _)| column_name(c).to_snake_case()).collect();
+
+        // Default name follows the single-column convention: idx_{table}_{col1}_{col2}
+        let name = decl.name.clone().unwrap_or_else(|| format!("idx_{}_{}", table_snake, column_names.join("_")));
+
+        let columns = decl.columns.iter().map(|(column, descending)| {
+            let column = column_name(column);
+            quote! {
+                bottle_orm::IndexColumn { name: #column, descending: #descending }
+            }
+        });
+
+        let unique = decl.unique;
+        let predicate = match &decl.predicate {
+            Some(p) => quote! { Some(#p) },
+            None => quote! { None },
+        };
+        let method = match &decl.method {
+            Some(m) => quote! { Some(#m) },
+            None => quote! { None },
+        };
+
+        quote! {
+            bottle_orm::IndexInfo {
+                name: #name,
+                columns: vec![#(#columns),*],
+                unique: #unique,
+                predicate: #predicate,
+                method: #method,
             }
         }
     });
 
+    let indexes_fn = if index_decls.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn indexes() -> Vec<bottle_orm::IndexInfo> {
+                vec![#(#index_defs),*]
+            }
+        }
+    };
+
     // ========================================================================
     // Generate Active Columns List
     // ========================================================================
 
-    let field_names_iter: Vec<_> = fields.named.iter().map(|f| &f.ident).collect();
+    let field_names_iter: Vec<_> = fields.named.iter().map(|f| column_name(f.ident.as_ref().unwrap())).collect();
 
     // ========================================================================
     // Generate to_map() Implementation
@@ -171,6 +428,7 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
 
     let map_inserts = fields.named.iter().map(|f| {
         let field_name = &f.ident;
+        let name_str = column_name(field_name.as_ref().unwrap());
         let field_type = &f.ty;
 
         let (_, is_nullable) = rust_type_to_sql(field_type);
@@ -179,7 +437,7 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
             return quote! {
                 if let Some(val) = &self.#field_name {
                     map.insert(
-                        stringify!(#field_name).to_string(),
+                        #name_str.to_string(),
                         val.to_string()
                     );
                 }
@@ -188,7 +446,7 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
 
         quote! {
             map.insert(
-                stringify!(#field_name).to_string(),
+                #name_str.to_string(),
                  self.#field_name.to_string()
             );
         }
@@ -199,13 +457,13 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
     // ========================================================================
     let table_name_str = struct_name.to_string().to_snake_case();
     let any_column_defs = fields.named.iter().map(|f| {
-        let field_name = &f.ident;
+        let name_str = column_name(f.ident.as_ref().unwrap());
         let field_type = &f.ty;
         let (sql_type, _) = rust_type_to_sql(field_type);
 
         quote! {
             bottle_orm::AnyInfo {
-                column: stringify!(#field_name),
+                column: #name_str,
                 sql_type: #sql_type,
                 table: #table_name_str,
             }
@@ -218,7 +476,7 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
     let from_row_logic = fields.named.iter().map(|f| {
         let field_name = &f.ident;
         let field_type = &f.ty;
-        let column_name = field_name.as_ref().unwrap().to_string();
+        let column_name = column_name(field_name.as_ref().unwrap());
         let alias_name = format!("{}__{}", table_name_str, column_name);
 
         let (sql_type, is_nullable) = rust_type_to_sql(field_type);
@@ -304,8 +562,8 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
     let module_name = format_ident!("{}_fields", struct_name.to_string().to_snake_case());
     let field_constants = fields.named.iter().map(|f| {
         let field_name = &f.ident;
-        let const_name = format_ident!("{}", field_name.as_ref().unwrap().to_string().to_uppercase());
-        let name_str = field_name.as_ref().unwrap().to_string();
+        let name_str = column_name(field_name.as_ref().unwrap());
+        let const_name = format_ident!("{}", name_str.to_uppercase());
         quote! {
             pub const #const_name: &'static str = #name_str;
         }
@@ -344,7 +602,7 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
             }
 
             fn active_columns() -> Vec<&'static str> {
-                vec![#(stringify!(#field_names_iter) ),*]
+                vec![#(#field_names_iter),*]
             }
 
             fn to_map(&self) -> std::collections::HashMap<String, String> {
@@ -352,6 +610,10 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
                  #(#map_inserts)*
                   map
             }
+
+            #primary_keys_fn
+
+            #indexes_fn
         }
 
         impl bottle_orm::AnyImpl for #struct_name {
@@ -0,0 +1,205 @@
+//! # Embedded Migrations Macro Implementation
+//!
+//! This module implements the `embed_migrations!` function-like macro.
+//! It scans a directory of versioned SQL files at compile time and generates a
+//! static slice of `SqlMigration` values whose scripts are embedded with `include_str!`.
+//!
+//! ## File Naming
+//!
+//! ```text
+//! V001__create_stats_view.up.sql           (required, all drivers)
+//! V001__create_stats_view.down.sql         (optional, all drivers)
+//! V001__create_stats_view.up.postgres.sql  (optional, PostgreSQL only)
+//! V001__create_stats_view.up.mysql.sql     (optional, MySQL only)
+//! V001__create_stats_view.up.sqlite.sql    (optional, SQLite only)
+//! ```
+
+// ============================================================================
+// External Crate Imports
+// ============================================================================
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+// ============================================================================
+// Parsed Migration Structures
+// ============================================================================
+
+/// Absolute paths of the script variants for one direction (up or down).
+#[derive(Default)]
+struct ScriptFiles {
+    default: Option<PathBuf>,
+    postgres: Option<PathBuf>,
+    mysql: Option<PathBuf>,
+    sqlite: Option<PathBuf>,
+}
+
+/// All files that belong to a single migration version.
+struct MigrationFiles {
+    name: String,
+    up: ScriptFiles,
+    down: ScriptFiles,
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Parses a file name such as `V001__add_view.up.postgres.sql`.
+///
+/// Returns `(version, name, direction, driver)` or `None` if the file does not
+/// follow the migration naming convention.
+fn parse_file_name(file_name: &str) -> Option<(u64, String, String, Option<String>)> {
+    let stem = file_name.strip_suffix(".sql")?;
+    let rest = stem.strip_prefix('V')?;
+    let (version, rest) = rest.split_once("__")?;
+    let version = version.parse::<u64>().ok()?;
+
+    let mut parts = rest.split('.');
+    let name = parts.next()?.to_string();
+    let direction = parts.next()?.to_string();
+    let driver = parts.next().map(|d| d.to_string());
+
+    if parts.next().is_some() || name.is_empty() {
+        return None;
+    }
+
+    Some((version, name, direction, driver))
+}
+
+/// Generates the `SqlScript` expression for one direction.
+fn script_tokens(files: &ScriptFiles) -> TokenStream {
+    let variant = |path: &Option<PathBuf>| match path {
+        Some(p) => {
+            let p = p.to_string_lossy().to_string();
+            quote! { Some(include_str!(#p)) }
+        }
+        None => quote! { None },
+    };
+
+    let default = variant(&files.default);
+    let postgres = variant(&files.postgres);
+    let mysql = variant(&files.mysql);
+    let sqlite = variant(&files.sqlite);
+
+    quote! {
+        bottle_orm::migration::SqlScript {
+            default: #default,
+            postgres: #postgres,
+            mysql: #mysql,
+            sqlite: #sqlite,
+        }
+    }
+}
+
+/// Reads the migration directory and groups files by version.
+fn collect_migrations(dir: &Path) -> Result<BTreeMap<u64, MigrationFiles>, String> {
+    let entries = std::fs::read_dir(dir).map_err(|e| format!("failed to read `{}`: {}", dir.display(), e))?;
+
+    let mut migrations: BTreeMap<u64, MigrationFiles> = BTreeMap::new();
+
+    for entry in entries {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+
+        if !path.is_file() {
+            continue;
+        }
+
+        let file_name = path.file_name().and_then(|f| f.to_str()).unwrap_or_default().to_string();
+        if !file_name.ends_with(".sql") {
+            continue;
+        }
+
+        let (version, name, direction, driver) = parse_file_name(&file_name).ok_or_else(|| {
+            format!("invalid migration file name `{}`, expected `V<version>__<name>.<up|down>[.<driver>].sql`", file_name)
+        })?;
+
+        let migration = migrations.entry(version).or_insert_with(|| MigrationFiles {
+            name: name.clone(),
+            up: ScriptFiles::default(),
+            down: ScriptFiles::default(),
+        });
+
+        if migration.name != name {
+            return Err(format!("migration version {} is used by both `{}` and `{}`", version, migration.name, name));
+        }
+
+        let script = match direction.as_str() {
+            "up" => &mut migration.up,
+            "down" => &mut migration.down,
+            other => return Err(format!("invalid direction `{}` in `{}`, expected `up` or `down`", other, file_name)),
+        };
+
+        let slot = match driver.as_deref() {
+            None => &mut script.default,
+            Some("postgres") => &mut script.postgres,
+            Some("mysql") => &mut script.mysql,
+            Some("sqlite") => &mut script.sqlite,
+            Some(other) => {
+                return Err(format!(
+                    "unknown driver `{}` in `{}`, expected `postgres`, `mysql` or `sqlite`",
+                    other, file_name
+                ));
+            }
+        };
+
+        let absolute = path.canonicalize().map_err(|e| e.to_string())?;
+        *slot = Some(absolute);
+    }
+
+    for (version, migration) in &migrations {
+        let up = &migration.up;
+        if up.default.is_none() && up.postgres.is_none() && up.mysql.is_none() && up.sqlite.is_none() {
+            return Err(format!("migration V{}__{} has no `.up.sql` script", version, migration.name));
+        }
+    }
+
+    Ok(migrations)
+}
+
+// ============================================================================
+// Macro Expansion Function
+// ============================================================================
+
+/// Expands the `embed_migrations!("dir")` macro.
+///
+/// The directory is resolved relative to `CARGO_MANIFEST_DIR` of the crate
+/// invoking the macro.
+pub fn expand(dir: LitStr) -> TokenStream {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+    let path = Path::new(&manifest_dir).join(dir.value());
+
+    let migrations = match collect_migrations(&path) {
+        Ok(m) => m,
+        Err(msg) => return syn::Error::new(dir.span(), msg).to_compile_error(),
+    };
+
+    let items = migrations.iter().map(|(version, migration)| {
+        let name = &migration.name;
+        let up = script_tokens(&migration.up);
+        let down = script_tokens(&migration.down);
+
+        quote! {
+            bottle_orm::migration::SqlMigration {
+                version: #version,
+                name: #name,
+                up: #up,
+                down: #down,
+            }
+        }
+    });
+
+    quote! {
+        {
+            const MIGRATIONS: &[bottle_orm::migration::SqlMigration] = &[#(#items),*];
+            MIGRATIONS
+        }
+    }
+}
@@ -22,6 +22,7 @@
 //! - **`lib.rs`** (this file): Entry point and macro definitions
 //! - **`derive_model.rs`**: Implementation of the Model derive macro
 //! - **`types.rs`**: Type mapping utilities (Rust → SQL)
+//! - **`embed_migrations.rs`**: Compile-time embedding of SQL migration files
 //!
 //! ## Usage
 //!
@@ -51,6 +52,28 @@
 //! ```
 //! Marks the field as the table's primary key. Generates `PRIMARY KEY` constraint.
 //!
+//! ### Composite Primary Key
+//! ```rust,ignore
+//! #[derive(Model)]
+//! #[orm(primary_key(order_id, product_id))]
+//! struct OrderItem {
+//!     order_id: i32,
+//!     product_id: i32,
+//! }
+//! ```
+//! Struct-level attribute that generates a table-level `PRIMARY KEY ("order_id", "product_id")`
+//! constraint. Marking several fields with `#[orm(primary_key)]` has the same effect.
+//!
+//! ### Struct-Level Index
+//! ```rust,ignore
+//! #[derive(Model)]
+//! #[orm(index(name = "idx_live_posts", columns(author_id, created_at desc), where = "deleted_at IS NULL"))]
+//! #[orm(index(columns(tags), using = "gin"))]
+//! struct Post { /* ... */ }
+//! ```
+//! Creates composite, descending, partial (`where`) or typed (`using`) indexes. Add `unique`
+//! for a unique index. The name defaults to `idx_<table>_<col1>_<col2>`.
+//!
 //! ### Unique Constraint
 //! ```rust,ignore
 //! #[orm(unique)]
@@ -101,6 +124,56 @@
 //! Excludes this field from query results by default. Returns a placeholder value
 //! instead of the actual data (`"omited"` for strings, `1970-01-01T00:00:00Z` for dates, etc.).
 //!
+//! ### Default Value
+//! ```rust,ignore
+//! #[orm(default = "'active'")]
+//! status: String,
+//! #[orm(default = 0)]
+//! attempts: i32,
+//! ```
+//! Sets the column `DEFAULT`. String literals are emitted verbatim as SQL expressions.
+//!
+//! ### Check Constraint
+//! ```rust,ignore
+//! #[orm(check = "age >= 0")]
+//! age: i32,
+//! ```
+//! Adds a column-level `CHECK (...)` constraint.
+//!
+//! ### Generated Column
+//! ```rust,ignore
+//! #[orm(generated = "price * quantity", stored)]
+//! total: f64,
+//! ```
+//! Declares a `GENERATED ALWAYS AS (...)` column (`VIRTUAL` unless `stored` is given).
+//! The database computes the value, so `insert()` and `updates()` skip it.
+//!
+//! ### Auto-Increment
+//! ```rust,ignore
+//! #[orm(primary_key, auto_increment)]
+//! id: i64,
+//! ```
+//! Lets the database assign the key: `GENERATED BY DEFAULT AS IDENTITY` on PostgreSQL,
+//! `AUTO_INCREMENT` on MySQL and `INTEGER PRIMARY KEY AUTOINCREMENT` on SQLite.
+//! `insert()` never sends this column.
+//!
+//! ### Driver-Specific Column Type
+//! ```rust,ignore
+//! #[orm(sql_type(postgres = "CITEXT", mysql = "VARCHAR(320)"))]
+//! email: String,
+//! ```
+//! Overrides the DDL type per driver. Drivers without an override use the mapped type,
+//! which is translated at table creation (e.g. `UUID` becomes `CHAR(36)` on MySQL and
+//! `TEXT` on SQLite).
+//!
+//! ### Sensitive Column
+//! ```rust,ignore
+//! #[orm(sensitive)]
+//! password_hash: String,
+//! ```
+//! Redacts values bound for this column in `to_sql_with_params()`, `interpolated_sql()`
+//! and debug logs. The real value is still sent to the database.
+//!
 //! ### Combining Attributes
 //! ```rust,ignore
 //! #[orm(size = 50, unique, index)]
@@ -215,7 +288,7 @@
 // ============================================================================
 
 use proc_macro::TokenStream;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, DeriveInput, LitStr};
 
 // ============================================================================
 // Module Declarations
@@ -239,6 +312,12 @@ mod derive_model;
 /// macro, facilitating the mapping of `AnyRow` results to Rust structs.
 mod derive_anyrow;
 
+/// Embedded SQL migrations module.
+///
+/// This module contains the logic for expanding the `embed_migrations!`
+/// macro, which embeds versioned `.sql` files into the binary.
+mod embed_migrations;
+
 // ============================================================================
 // Procedural Macro Definitions
 // ============================================================================
@@ -262,6 +341,17 @@ mod derive_anyrow;
 /// * `size = N` - Sets column size (VARCHAR(N))
 /// * `foreign_key = "Table::Column"` - Defines a Foreign Key relationship
 /// * `omit` - Excludes field from queries (returns placeholder value)
+/// * `default = "..."` - Sets the column DEFAULT (string literals are raw SQL)
+/// * `check = "..."` - Adds a CHECK constraint
+/// * `generated = "...", stored` - Declares a generated column, skipped on insert/update
+/// * `auto_increment` - Database-assigned integer column, skipped on insert
+/// * `sql_type(postgres = "...", mysql = "...", sqlite = "...")` - Per-driver DDL type overrides
+/// * `sensitive` - Redacts bound values in debug output
+///
+/// The following `#[orm(...)]` attributes are recognized on the struct itself:
+///
+/// * `primary_key(col_a, col_b)` - Declares a composite primary key
+/// * `index(name = "...", columns(a, b desc), unique, where = "...", using = "...")` - Declares an index
 ///
 /// # Type Mapping
 ///
@@ -377,3 +467,56 @@ pub fn any_derive(input: TokenStream) -> TokenStream {
     let expanded = derive_anyrow::expand(ast);
     TokenStream::from(expanded)
 }
+
+/// Embeds a directory of versioned SQL migration files into the binary.
+///
+/// Scans the given directory (relative to the invoking crate's `CARGO_MANIFEST_DIR`)
+/// at compile time and expands to a `&'static [SqlMigration]` that can be passed to
+/// `Migrator::register_sql()`. Each script is embedded with `include_str!`, so the
+/// files do not need to be shipped alongside the binary.
+///
+/// # File Naming
+///
+/// Files must follow the pattern `V<version>__<name>.<up|down>[.<driver>].sql`:
+///
+/// * `V001__create_stats_view.up.sql` - Applied on every driver (required)
+/// * `V001__create_stats_view.down.sql` - Reverts the migration (optional)
+/// * `V001__create_stats_view.up.postgres.sql` - Used instead of the generic script on PostgreSQL
+///
+/// Supported driver suffixes are `postgres`, `mysql` and `sqlite`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use bottle_orm::{embed_migrations, Database};
+///
+/// db.migrator()
+///     .register::<User>()
+///     .register_sql(embed_migrations!("migrations/"))
+///     .run()
+///     .await?;
+/// ```
+///
+/// # Rebuilding
+///
+/// Cargo only tracks the files that are already embedded, so adding, removing or
+/// renaming a migration does not trigger a rebuild on its own. Watch the directory
+/// from the invoking crate's `build.rs`:
+///
+/// ```rust,ignore
+/// // build.rs
+/// fn main() {
+///     println!("cargo:rerun-if-changed=migrations");
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Compilation fails if the directory cannot be read, a `.sql` file does not
+/// follow the naming convention, two names share the same version, or a
+/// version has no `up` script.
+#[proc_macro]
+pub fn embed_migrations(input: TokenStream) -> TokenStream {
+    let dir = parse_macro_input!(input as LitStr);
+    TokenStream::from(embed_migrations::expand(dir))
+}
@@ -3,6 +3,9 @@
 //! This module handles the conversion between Rust types and their corresponding SQL types.
 //! It supports standard Rust primitives, chrono datetime types, and UUID types (versions 1-7).
 //!
+//! The mapped types are *logical* types (PostgreSQL spelling). They are translated
+//! to each driver's DDL type at table creation by `ColumnInfo::ddl_type`.
+//!
 //! ## Supported Type Mappings
 //!
 //! ### Primitive Types
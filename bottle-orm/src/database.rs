@@ -45,18 +45,26 @@
 // External Crate Imports
 // ============================================================================
 
+use futures::future::BoxFuture;
 use heck::ToSnakeCase;
 use sqlx::{
     any::{AnyArguments, AnyPoolOptions},
-    AnyPool, Arguments, Error, Row,
+    AnyConnection, AnyPool, Arguments, Row,
 };
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 // ============================================================================
 // Internal Crate Imports
 // ============================================================================
 
-use crate::{migration::Migrator, model::Model, query_builder::QueryBuilder, Transaction};
+use crate::{
+    dialect::SqlDialect,
+    migration::Migrator,
+    model::{IndexInfo, Model},
+    query_builder::QueryBuilder,
+    transaction::{self, RetryPolicy, TxOptions},
+    Error, Transaction,
+};
 
 // ============================================================================
 // Database Driver Enumeration
@@ -100,7 +108,7 @@ use crate::{migration::Migrator, model::Model, query_builder::QueryBuilder, Tran
 ///     Drivers::MySQL => println!("Using MySQL"),
 /// }
 /// ```
-#[derive(Clone, Debug, Copy)]
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
 pub enum Drivers {
     /// PostgreSQL driver.
     ///
@@ -131,6 +139,37 @@ pub enum Drivers {
     MySQL,
 }
 
+impl Drivers {
+    /// Quotes an SQL identifier (table, column, index or constraint name).
+    ///
+    /// Shorthand for `self.dialect().quote_ident(ident)`: MySQL uses backticks,
+    /// PostgreSQL and SQLite use standard double quotes. Embedded quote
+    /// characters are escaped by doubling them.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// assert_eq!(Drivers::Postgres.quote_ident("user"), "\"user\"");
+    /// assert_eq!(Drivers::MySQL.quote_ident("user"), "`user`");
+    /// ```
+    pub fn quote_ident(&self, ident: &str) -> String {
+        self.dialect().quote_ident(ident)
+    }
+
+    /// Quotes a column reference that may be qualified with a table name.
+    ///
+    /// `"table.column"` is quoted as two identifiers; a bare name is quoted as one.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// assert_eq!(Drivers::MySQL.quote_column("user.id"), "`user`.`id`");
+    /// ```
+    pub fn quote_column(&self, col: &str) -> String {
+        self.dialect().quote_column(col)
+    }
+}
+
 // ============================================================================
 // Database Builder
 // ============================================================================
@@ -153,6 +192,7 @@ pub enum Drivers {
 #[derive(Debug)]
 pub struct DatabaseBuilder {
     options: AnyPoolOptions,
+    dialect: Option<Arc<dyn SqlDialect>>,
 }
 
 impl DatabaseBuilder {
@@ -186,6 +226,22 @@ impl DatabaseBuilder {
         self
     }
 
+    /// Overrides the SQL dialect used to generate statements.
+    ///
+    /// By default the dialect is chosen from the URL scheme. Use this to connect
+    /// to engines that are wire-compatible with PostgreSQL, MySQL or SQLite but
+    /// need different syntax. The dialect's `driver()` must match the URL scheme.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let db = Database::builder().dialect(CockroachDialect).connect("postgres://...").await?;
+    /// ```
+    pub fn dialect<D: SqlDialect + 'static>(mut self, dialect: D) -> Self {
+        self.dialect = Some(Arc::new(dialect));
+        self
+    }
+
     /// Connects to the database using the configured options.
     pub async fn connect(self, url: &str) -> Result<Database, Error> {
         // Install default drivers for sqlx::Any
@@ -201,7 +257,15 @@ impl DatabaseBuilder {
             _ => Drivers::SQLite,
         };
 
-        Ok(Database { pool, driver })
+        let dialect = self.dialect.unwrap_or_else(|| driver.dialect());
+        if dialect.driver() != driver {
+            return Err(Error::InvalidArgument(format!(
+                "dialect {:?} is not compatible with a {:?} connection",
+                dialect, driver
+            )));
+        }
+
+        Ok(Database { pool, driver, dialect })
     }
 }
 
@@ -219,6 +283,7 @@ impl DatabaseBuilder {
 ///
 /// * `pool` - The sqlx connection pool for executing queries
 /// * `driver` - The detected database driver type
+/// * `dialect` - The SQL dialect used to generate statements
 ///
 /// # Thread Safety
 ///
@@ -265,6 +330,12 @@ pub struct Database {
     /// Used to generate driver-specific SQL syntax (e.g., placeholders,
     /// type casting, schema queries).
     pub(crate) driver: Drivers,
+
+    /// The SQL dialect used to render statements.
+    ///
+    /// Defaults to the built-in dialect of `driver`; replaceable through
+    /// `DatabaseBuilder::dialect()`.
+    pub(crate) dialect: Arc<dyn SqlDialect>,
 }
 
 // ============================================================================
@@ -290,7 +361,7 @@ impl Database {
     ///     .await?;
     /// ```
     pub fn builder() -> DatabaseBuilder {
-        DatabaseBuilder { options: AnyPoolOptions::new() }
+        DatabaseBuilder { options: AnyPoolOptions::new(), dialect: None }
     }
 
     /// Connects to the database using a connection string (Database URL).
@@ -319,7 +390,7 @@ impl Database {
     /// # Returns
     ///
     /// * `Ok(Database)` - Successfully connected database instance
-    /// * `Err(sqlx::Error)` - Connection error (invalid URL, authentication failure, etc.)
+    /// * `Err(Error)` - Connection error (invalid URL, authentication failure, etc.)
     ///
     /// # Examples
     ///
@@ -440,7 +511,7 @@ impl Database {
         }
 
         // Create and return the query builder
-        QueryBuilder::new(self.clone(), self.driver, T::table_name(), T::columns(), columns)
+        QueryBuilder::new(self.clone(), self.dialect.clone(), T::table_name(), T::columns(), columns)
     }
 
     /// Creates a raw SQL query builder.
@@ -493,15 +564,25 @@ impl Database {
     /// # Returns
     ///
     /// * `Ok(&Self)` - Reference to self for method chaining
-    /// * `Err(sqlx::Error)` - Database error during table creation
+    /// * `Err(Error)` - Database error during table creation
     ///
     /// # Generated SQL Features
     ///
-    /// - **Primary Keys**: Automatically marked with `PRIMARY KEY`
+    /// - **Column Types**: Logical types are translated per driver (see `ColumnInfo::ddl_type`)
+    ///   and can be overridden with `#[orm(sql_type(postgres = "...", mysql = "..."))]`
+    /// - **Primary Keys**: Automatically marked with `PRIMARY KEY`; composite keys
+    ///   become a table-level `PRIMARY KEY ("a", "b")` constraint
     /// - **NOT NULL**: Non-nullable fields get `NOT NULL` constraint
     /// - **UNIQUE**: Fields marked with `#[orm(unique)]` get `UNIQUE` constraint
-    /// - **DEFAULT**: Fields marked with `#[orm(create_time)]` get `DEFAULT CURRENT_TIMESTAMP`
+    /// - **DEFAULT**: Fields marked with `#[orm(create_time)]` get `DEFAULT CURRENT_TIMESTAMP`,
+    ///   `#[orm(default = "...")]` sets an explicit default
+    /// - **CHECK**: Fields marked with `#[orm(check = "...")]` get a `CHECK` constraint
+    /// - **Generated Columns**: `#[orm(generated = "...", stored)]` emits `GENERATED ALWAYS AS (...)`
+    /// - **Auto-Increment**: `#[orm(auto_increment)]` emits `GENERATED BY DEFAULT AS IDENTITY` (PostgreSQL),
+    ///   `AUTO_INCREMENT` (MySQL) or `INTEGER PRIMARY KEY AUTOINCREMENT` (SQLite)
     /// - **Indexes**: Fields marked with `#[orm(index)]` get database indexes
+    /// - **Struct-Level Indexes**: `#[orm(index(...))]` declarations create composite,
+    ///   descending, partial (`WHERE`) and typed (`USING`) indexes
     ///
     /// # Example
     ///
@@ -544,85 +625,22 @@ impl Database {
     /// CREATE INDEX IF NOT EXISTS "idx_user_email" ON "user" ("email");
     /// ```
     pub async fn create_table<T: Model>(&self) -> Result<&Self, Error> {
-        // Get table name in snake_case format
-        let table_name = T::table_name().to_snake_case();
-        let columns = T::columns();
-
-        let mut column_defs = Vec::new();
-        let mut index_statements = Vec::new();
-
-        // Build column definitions
-        for col in &columns {
-            // Strip 'r#' prefix if present (for Rust keywords used as column names)
-            let col_name = col.name.strip_prefix("r#").unwrap_or(col.name).to_snake_case();
-            let mut def = format!("\"{}\" {}", col_name, col.sql_type);
-
-            // Add PRIMARY KEY constraint
-            if col.is_primary_key {
-                def.push_str(" PRIMARY KEY");
-            }
-
-            // Add NOT NULL constraint (except for primary keys, which are implicitly NOT NULL)
-            if !col.is_nullable && !col.is_primary_key {
-                def.push_str(" NOT NULL");
-            }
-
-            // Add DEFAULT CURRENT_TIMESTAMP for create_time fields
-            if col.create_time {
-                def.push_str(" DEFAULT CURRENT_TIMESTAMP");
-            }
-
-            // Add UNIQUE constraint
-            if col.unique {
-                def.push_str(" UNIQUE");
-            }
-
-            column_defs.push(def);
-
-            // Generate index creation statement if needed
-            if col.index {
-                let index_type = if col.unique { "UNIQUE INDEX" } else { "INDEX" };
-                let index_name = format!("idx_{}_{}", table_name, col_name);
-
-                let index_query = format!(
-                    "CREATE {} IF NOT EXISTS \"{}\" ON \"{}\" (\"{}\")",
-                    index_type, index_name, table_name, col_name,
-                );
-
-                index_statements.push(index_query);
-            }
-        }
-
-        // Add SQLite Foreign Keys inline (SQLite doesn't support ADD CONSTRAINT)
-        if let Drivers::SQLite = self.driver {
-            for col in &columns {
-                if let (Some(f_table), Some(f_key)) = (col.foreign_table, col.foreign_key) {
-                    let col_name = col.name.strip_prefix("r#").unwrap_or(col.name).to_snake_case();
-                    let f_table_clean = f_table.to_snake_case();
-                    let f_key_clean = f_key.to_snake_case();
-
-                    let fk_def = format!(
-                        "FOREIGN KEY (\"{}\") REFERENCES \"{}\" (\"{}\")",
-                        col_name, f_table_clean, f_key_clean
-                    );
-                    column_defs.push(fk_def);
-                }
-            }
-        }
-
-        // Build and execute CREATE TABLE statement
-        let create_table_query =
-            format!("CREATE TABLE IF NOT EXISTS \"{}\" ({})", table_name.to_snake_case(), column_defs.join(", "));
-        log::info!("{}", create_table_query);
-
-        sqlx::query(&create_table_query).execute(&self.pool).await?;
+        let mut conn = self.pool.acquire().await?;
+        self.create_table_on::<T>(&mut conn).await?;
+        Ok(self)
+    }
 
-        // Create indexes
-        for idx_stmt in index_statements {
-            sqlx::query(&idx_stmt).execute(&self.pool).await?;
+    /// Creates the table for model `T` on `conn`.
+    ///
+    /// Shared by `Database::create_table()`, `Transaction::create_table()` and
+    /// the `Migrator`, so that DDL can run inside a transaction.
+    pub(crate) async fn create_table_on<T: Model>(&self, conn: &mut AnyConnection) -> Result<(), Error> {
+        for statement in create_table_statements::<T>(self.dialect.as_ref())? {
+            log::info!("{}", statement);
+            sqlx::query(&statement).execute(&mut *conn).await?;
         }
 
-        Ok(self)
+        Ok(())
     }
 
     /// Starts a new database transaction.
@@ -634,7 +652,7 @@ impl Database {
     /// # Returns
     ///
     /// * `Ok(Transaction)` - A new transaction instance
-    /// * `Err(sqlx::Error)` - Database error starting transaction
+    /// * `Err(Error)` - Database error starting transaction
     ///
     /// # Example
     ///
@@ -643,9 +661,127 @@ impl Database {
     /// // ... perform operations ...
     /// tx.commit().await?;
     /// ```
-    pub async fn begin<'a>(&self) -> Result<Transaction<'a>, sqlx::Error> {
+    pub async fn begin<'a>(&self) -> Result<Transaction<'a>, Error> {
         let tx = self.pool.begin().await?;
-        Ok(Transaction { tx, driver: self.driver })
+        Ok(Transaction { tx, db: self.clone() })
+    }
+
+    /// Starts a new database transaction with an isolation level and access mode.
+    ///
+    /// The opening statements are rendered by the dialect: `BEGIN ISOLATION LEVEL
+    /// ..., READ ONLY, DEFERRABLE` on PostgreSQL, `SET TRANSACTION ISOLATION LEVEL
+    /// ...` followed by `START TRANSACTION [READ ONLY]` on MySQL. SQLite transactions
+    /// are always serializable, so the isolation level is ignored there, and SQLite has
+    /// no read-only transactions, so `read_only` is rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Isolation level, read-only and deferrable flags
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Transaction)` - A new transaction instance
+    /// * `Err(Error::InvalidArgument)` - `read_only` was requested on a dialect without read-only transactions
+    /// * `Err(Error)` - Database error starting transaction
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use bottle_orm::{IsolationLevel, TxOptions};
+    ///
+    /// let mut tx = db.begin_with(TxOptions {
+    ///     isolation: Some(IsolationLevel::Serializable),
+    ///     ..Default::default()
+    /// }).await?;
+    /// // ... transfer funds ...
+    /// tx.commit().await?;
+    /// ```
+    pub async fn begin_with<'a>(&self, options: TxOptions) -> Result<Transaction<'a>, Error> {
+        if options.read_only && !self.dialect.supports_read_only_transactions() {
+            return Err(Error::InvalidArgument("read-only transactions are not supported by this database".to_string()));
+        }
+        let statements = self.dialect.begin_statements(&options);
+        let mut conn = self.pool.acquire().await?;
+
+        let tx = match statements.split_last() {
+            Some((begin, setup)) => {
+                for statement in setup {
+                    sqlx::Executor::execute(&mut *conn, statement.as_str()).await?;
+                }
+                sqlx::Transaction::begin(conn, Some(begin.clone().into())).await?
+            }
+            None => sqlx::Transaction::begin(conn, None).await?,
+        };
+        Ok(Transaction { tx, db: self.clone() })
+    }
+
+    /// Runs a closure inside a transaction.
+    ///
+    /// The transaction is committed when the closure returns `Ok` and rolled back
+    /// when it returns `Err` or panics (the panic is then resumed). The closure
+    /// returns a boxed future (`Box::pin(async move { ... })`) so it can borrow
+    /// the transaction; anything else the future uses must be owned, e.g. a
+    /// cloned `Database`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The unit of work; its error type must convert from `bottle_orm::Error`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` - The closure's value, after a successful commit
+    /// * `Err(E)` - The closure's error, or the begin/commit error
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let post_id = db.transaction(|tx| Box::pin(async move {
+    ///     tx.model::<User>().insert(&user).await?;
+    ///     tx.model::<Post>().insert(&post).await?;
+    ///     Ok::<_, bottle_orm::Error>(post.id)
+    /// })).await?;
+    /// ```
+    pub async fn transaction<T, E, F>(&self, f: F) -> Result<T, E>
+    where
+        F: for<'t> FnMut(&'t mut Transaction<'static>) -> BoxFuture<'t, Result<T, E>> + Send,
+        T: Send,
+        E: From<Error> + std::error::Error + Send + 'static,
+    {
+        transaction::run(self, RetryPolicy::none(), f).await
+    }
+
+    /// Runs a closure inside a transaction, retrying it on serialization failures and deadlocks.
+    ///
+    /// Behaves like [`transaction()`](Self::transaction), but when the closure or
+    /// the commit fails with a retryable error (SQLSTATE `40001` / `40P01`, MySQL
+    /// error 1213 or `SQLITE_BUSY`, found anywhere in the error's source chain) the
+    /// transaction is rolled back and the closure runs again, up to the limit set
+    /// by `policy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - Maximum retries and backoff between attempts
+    /// * `f` - The unit of work; it may run several times
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use bottle_orm::RetryPolicy;
+    ///
+    /// db.transaction_with_retry(RetryPolicy::new(5), |tx| Box::pin(async move {
+    ///     let from: Account = tx.model::<Account>().filter("id", Op::Eq, 1).lock_for_update().first().await?;
+    ///     tx.model::<Account>().filter("id", Op::Eq, 1).update("balance", from.balance - 100).await?;
+    ///     tx.model::<Account>().filter("id", Op::Eq, 2).update("balance", to_balance + 100).await?;
+    ///     Ok::<_, bottle_orm::Error>(())
+    /// })).await?;
+    /// ```
+    pub async fn transaction_with_retry<T, E, F>(&self, policy: RetryPolicy, f: F) -> Result<T, E>
+    where
+        F: for<'t> FnMut(&'t mut Transaction<'static>) -> BoxFuture<'t, Result<T, E>> + Send,
+        T: Send,
+        E: From<Error> + std::error::Error + Send + 'static,
+    {
+        transaction::run(self, policy, f).await
     }
 
     // ========================================================================
@@ -665,7 +801,7 @@ impl Database {
     /// # Returns
     ///
     /// * `Ok(&Self)` - Reference to self for method chaining
-    /// * `Err(sqlx::Error)` - Database error during foreign key creation
+    /// * `Err(Error)` - Database error during foreign key creation
     ///
     /// # Constraint Naming
     ///
@@ -727,6 +863,18 @@ impl Database {
             return Ok(self);
         }
 
+        let mut conn = self.pool.acquire().await?;
+        self.assign_foreign_keys_on::<T>(&mut conn).await?;
+        Ok(self)
+    }
+
+    /// Assigns the foreign key constraints of model `T` on `conn`.
+    pub(crate) async fn assign_foreign_keys_on<T: Model>(&self, conn: &mut AnyConnection) -> Result<(), Error> {
+        // SQLite handles FKs in create_table, so we skip here
+        if let Drivers::SQLite = self.driver {
+            return Ok(());
+        }
+
         // Get table name in snake_case format
         let table_name = T::table_name().to_snake_case();
         let columns = T::columns();
@@ -747,12 +895,12 @@ impl Database {
                     Drivers::Postgres => {
                         let check_query =
                             "SELECT count(*) FROM information_schema.table_constraints WHERE constraint_name = $1";
-                        let row = sqlx::query(check_query).bind(&constraint_name).fetch_one(&self.pool).await?;
+                        let row = sqlx::query(check_query).bind(&constraint_name).fetch_one(&mut *conn).await?;
                         row.try_get(0).unwrap_or(0)
                     }
                     Drivers::MySQL => {
                         let check_query = "SELECT count(*) FROM information_schema.TABLE_CONSTRAINTS WHERE CONSTRAINT_NAME = ? AND TABLE_SCHEMA = DATABASE()";
-                        let row = sqlx::query(check_query).bind(&constraint_name).fetch_one(&self.pool).await?;
+                        let row = sqlx::query(check_query).bind(&constraint_name).fetch_one(&mut *conn).await?;
                         row.try_get(0).unwrap_or(0)
                     }
                     Drivers::SQLite => 0, // Unreachable
@@ -765,15 +913,19 @@ impl Database {
 
                 // Create foreign key constraint
                 let alter_query = format!(
-                    "ALTER TABLE \"{}\" ADD CONSTRAINT \"{}\" FOREIGN KEY (\"{}\") REFERENCES \"{}\" (\"{}\")",
-                    table_name, constraint_name, col_name, f_table_clean, f_key_clean
+                    "ALTER TABLE {} ADD CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} ({})",
+                    self.dialect.quote_ident(&table_name),
+                    self.dialect.quote_ident(&constraint_name),
+                    self.dialect.quote_ident(&col_name),
+                    self.dialect.quote_ident(&f_table_clean),
+                    self.dialect.quote_ident(&f_key_clean)
                 );
 
-                sqlx::query(&alter_query).execute(&self.pool).await?;
+                sqlx::query(&alter_query).execute(&mut *conn).await?;
             }
         }
 
-        Ok(self)
+        Ok(())
     }
 }
 
@@ -787,10 +939,15 @@ pub trait Connection {
     ///
     /// This uses GATs to bind the lifetime of the executor (`'c`) to the lifetime
     /// of the borrow of the connection (`&'c mut self`).
-    type Exec<'c>: sqlx::Executor<'c, Database = sqlx::Any>
+    ///
+    /// It can also begin a transaction (a savepoint when already inside one).
+    type Exec<'c>: sqlx::Executor<'c, Database = sqlx::Any> + sqlx::Acquire<'c, Database = sqlx::Any>
     where
         Self: 'c;
 
+    /// Whether statements run inside a transaction (required for row locks).
+    const IS_TRANSACTION: bool = false;
+
     /// Returns a mutable reference to the SQLx executor.
     ///
     /// # Returns
@@ -811,7 +968,7 @@ impl Connection for Database {
 }
 
 /// Implementation of Connection for a mutable reference to Database.
-impl<'a> Connection for &'a mut Database {
+impl Connection for &mut Database {
     type Exec<'c>
         = &'c sqlx::Pool<sqlx::Any>
     where
@@ -829,11 +986,278 @@ impl<'a> Connection for &mut sqlx::Transaction<'a, sqlx::Any> {
     where
         Self: 'c;
 
+    const IS_TRANSACTION: bool = true;
+
     fn executor<'c>(&'c mut self) -> Self::Exec<'c> {
         &mut **self
     }
 }
 
+// ============================================================================
+// Schema Generation
+// ============================================================================
+
+/// Compiles the `CREATE TABLE` and `CREATE INDEX` statements for model `T`.
+///
+/// This is the DDL that `Database::create_table()` and the `Migrator` execute,
+/// rendered for `dialect` without touching a database.
+///
+/// # Arguments
+///
+/// * `dialect` - The SQL dialect to render the statements for
+///
+/// # Returns
+///
+/// * `Ok(Vec<String>)` - The `CREATE TABLE` statement followed by its index statements
+/// * `Err(Error)` - The model uses a feature the dialect cannot express
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use bottle_orm::{database::create_table_statements, dialect::MySqlDialect};
+///
+/// for statement in create_table_statements::<User>(&MySqlDialect)? {
+///     println!("{};", statement);
+/// }
+/// ```
+pub fn create_table_statements<T: Model>(dialect: &dyn SqlDialect) -> Result<Vec<String>, Error> {
+    // Get table name in snake_case format
+    let table_name = T::table_name().to_snake_case();
+    let columns = T::columns();
+    let primary_keys = T::primary_keys();
+
+    // Composite keys cannot be declared inline and need a table-level constraint
+    let composite_pk = primary_keys.len() > 1;
+
+    let indexes = T::indexes();
+
+    let mut column_defs = Vec::new();
+    let mut index_statements = Vec::new();
+
+    // Build column definitions
+    for col in &columns {
+        // Strip 'r#' prefix if present (for Rust keywords used as column names)
+        let col_name = col.name.strip_prefix("r#").unwrap_or(col.name).to_snake_case();
+        let inline_pk = col.is_primary_key && !composite_pk;
+
+        // Columns used in keys or indexes may need a sized type (MySQL)
+        let keyed = col.is_primary_key
+            || col.unique
+            || col.index
+            || col.foreign_key.is_some()
+            || indexes.iter().any(|idx| idx.columns.iter().any(|c| c.name == col.name));
+
+        // SQLite only allows AUTOINCREMENT on an inline `INTEGER PRIMARY KEY`
+        let sql_type = match dialect.driver() {
+            Drivers::SQLite if col.auto_increment && inline_pk => "INTEGER".to_string(),
+            _ => col.ddl_type(dialect, keyed),
+        };
+        let mut def = format!("{} {}", dialect.quote_ident(&col_name), sql_type);
+
+        // Generated column expressions must directly follow the type (MySQL)
+        if let Some(expr) = col.generated {
+            def.push_str(&dialect.generated_column(expr, col.stored));
+        }
+
+        // Add identity clause for auto-increment columns on PostgreSQL
+        if col.auto_increment && matches!(dialect.driver(), Drivers::Postgres) {
+            def.push_str(" GENERATED BY DEFAULT AS IDENTITY");
+        }
+
+        // Add PRIMARY KEY constraint
+        if inline_pk {
+            def.push_str(" PRIMARY KEY");
+        }
+
+        // Add driver-specific auto-increment keyword
+        if col.auto_increment {
+            match dialect.driver() {
+                Drivers::MySQL => def.push_str(" AUTO_INCREMENT"),
+                Drivers::SQLite if inline_pk => def.push_str(" AUTOINCREMENT"),
+                Drivers::SQLite => {
+                    return Err(Error::InvalidArgument(format!(
+                        "auto_increment column \"{}\" must be the only primary key on SQLite",
+                        col_name
+                    )));
+                }
+                _ => {}
+            }
+        }
+
+        // Add NOT NULL constraint (inline primary keys are implicitly NOT NULL)
+        if !col.is_nullable && !inline_pk {
+            def.push_str(" NOT NULL");
+        }
+
+        // Add DEFAULT clause (generated columns cannot carry one)
+        if col.generated.is_none() {
+            if let Some(default) = col.default {
+                def.push_str(&format!(" DEFAULT {}", default));
+            } else if col.create_time {
+                // Add DEFAULT CURRENT_TIMESTAMP for create_time fields
+                def.push_str(" DEFAULT CURRENT_TIMESTAMP");
+            }
+        }
+
+        // Add UNIQUE constraint
+        if col.unique {
+            def.push_str(" UNIQUE");
+        }
+
+        // Add CHECK constraint
+        if let Some(check) = col.check {
+            def.push_str(&format!(" CHECK ({})", check));
+        }
+
+        column_defs.push(def);
+
+        // Generate index creation statement if needed
+        if col.index {
+            let index_type = if col.unique { "UNIQUE INDEX" } else { "INDEX" };
+            let index_name = format!("idx_{}_{}", table_name, col_name);
+
+            let index_query = format!(
+                "CREATE {} IF NOT EXISTS {} ON {} ({})",
+                index_type,
+                dialect.quote_ident(&index_name),
+                dialect.quote_ident(&table_name),
+                dialect.quote_ident(&col_name),
+            );
+
+            index_statements.push(index_query);
+        }
+    }
+
+    // Generate struct-level index statements
+    for index in &indexes {
+        index_statements.push(index_statement(dialect, &table_name, index)?);
+    }
+
+    // Add composite PRIMARY KEY as a table-level constraint
+    if composite_pk {
+        let pk_cols: Vec<String> = primary_keys
+            .iter()
+            .map(|pk| dialect.quote_ident(&pk.strip_prefix("r#").unwrap_or(pk).to_snake_case()))
+            .collect();
+        column_defs.push(format!("PRIMARY KEY ({})", pk_cols.join(", ")));
+    }
+
+    // Add SQLite Foreign Keys inline (SQLite doesn't support ADD CONSTRAINT)
+    if let Drivers::SQLite = dialect.driver() {
+        for col in &columns {
+            if let (Some(f_table), Some(f_key)) = (col.foreign_table, col.foreign_key) {
+                let col_name = col.name.strip_prefix("r#").unwrap_or(col.name).to_snake_case();
+                let f_table_clean = f_table.to_snake_case();
+                let f_key_clean = f_key.to_snake_case();
+
+                let fk_def = format!(
+                    "FOREIGN KEY ({}) REFERENCES {} ({})",
+                    dialect.quote_ident(&col_name),
+                    dialect.quote_ident(&f_table_clean),
+                    dialect.quote_ident(&f_key_clean)
+                );
+                column_defs.push(fk_def);
+            }
+        }
+    }
+
+    // Build CREATE TABLE statement, followed by its indexes
+    let create_table_query =
+        format!("CREATE TABLE IF NOT EXISTS {} ({})", dialect.quote_ident(&table_name), column_defs.join(", "));
+
+    let mut statements = vec![create_table_query];
+    statements.extend(index_statements);
+    Ok(statements)
+}
+
+/// Builds the `CREATE INDEX` statement for a struct-level index declaration.
+///
+/// The index method is placed where each dialect expects it and dropped on
+/// SQLite. Partial indexes are rejected on MySQL, which cannot express them.
+fn index_statement(dialect: &dyn SqlDialect, table_name: &str, index: &IndexInfo) -> Result<String, Error> {
+    let index_type = if index.unique { "UNIQUE INDEX" } else { "INDEX" };
+
+    let columns: Vec<String> = index
+        .columns
+        .iter()
+        .map(|c| {
+            let col_name = c.name.strip_prefix("r#").unwrap_or(c.name).to_snake_case();
+            let quoted = dialect.quote_ident(&col_name);
+            if c.descending { format!("{} DESC", quoted) } else { quoted }
+        })
+        .collect();
+
+    let mut query = format!(
+        "CREATE {} IF NOT EXISTS {} ON {}",
+        index_type,
+        dialect.quote_ident(index.name),
+        dialect.quote_ident(table_name)
+    );
+
+    match (dialect.driver(), index.method) {
+        (Drivers::Postgres, Some(method)) => {
+            query.push_str(&format!(" USING {} ({})", method, columns.join(", ")));
+        }
+        (Drivers::MySQL, Some(method)) => {
+            query.push_str(&format!(" ({}) USING {}", columns.join(", "), method.to_uppercase()));
+        }
+        _ => query.push_str(&format!(" ({})", columns.join(", "))),
+    }
+
+    if let Some(predicate) = index.predicate {
+        if let Drivers::MySQL = dialect.driver() {
+            return Err(Error::InvalidArgument(format!(
+                "partial index \"{}\" is not supported on MySQL",
+                index.name
+            )));
+        }
+        query.push_str(&format!(" WHERE {}", predicate));
+    }
+
+    Ok(query)
+}
+
+// ============================================================================
+// Model Connection Trait
+// ============================================================================
+
+/// A connection that starts queries for a model: `&Database` or `&mut Transaction`.
+///
+/// The active record methods of `Model` (`save()`, `delete()`, `reload()` and
+/// `find()`) take any `ModelConnection`, so the same call works on the pool and
+/// inside a transaction.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// user.save(&db).await?;
+///
+/// let mut tx = db.begin().await?;
+/// user.save(&mut tx).await?;
+/// tx.commit().await?;
+/// ```
+pub trait ModelConnection: Send {
+    /// The connection the query builders execute on.
+    type Conn<'c>: Connection + Send
+    where
+        Self: 'c;
+
+    /// Starts building a query for `T`, like `Database::model()` and `Transaction::model()`.
+    fn model<T: Model + Send + Sync + Unpin>(&mut self) -> QueryBuilder<'_, T, Self::Conn<'_>>;
+}
+
+/// Queries run on the connection pool.
+impl ModelConnection for &Database {
+    type Conn<'c>
+        = Database
+    where
+        Self: 'c;
+
+    fn model<T: Model + Send + Sync + Unpin>(&mut self) -> QueryBuilder<'_, T, Self::Conn<'_>> {
+        Database::model(self)
+    }
+}
+
 // ============================================================================
 // Raw SQL Query Builder
 // ============================================================================
@@ -871,51 +1295,71 @@ where
     }
 
     /// Executes the query and returns all matching rows mapped to type `T`.
-    pub async fn fetch_all<T>(mut self) -> Result<Vec<T>, Error>
+    pub fn fetch_all<T>(mut self) -> BoxFuture<'a, Result<Vec<T>, Error>>
     where
-        T: for<'r> sqlx::FromRow<'r, sqlx::any::AnyRow> + Send + Unpin,
+        C: 'a,
+        T: for<'r> sqlx::FromRow<'r, sqlx::any::AnyRow> + Send + Unpin + 'a,
     {
-        sqlx::query_as_with::<_, T, _>(self.sql, self.args).fetch_all(self.conn.executor()).await
+        Box::pin(async move {
+            Ok(sqlx::query_as_with::<_, T, _>(self.sql, self.args).fetch_all(self.conn.executor()).await?)
+        })
     }
 
     /// Executes the query and returns the first matching row mapped to type `T`.
-    pub async fn fetch_one<T>(mut self) -> Result<T, Error>
+    pub fn fetch_one<T>(mut self) -> BoxFuture<'a, Result<T, Error>>
     where
-        T: for<'r> sqlx::FromRow<'r, sqlx::any::AnyRow> + Send + Unpin,
+        C: 'a,
+        T: for<'r> sqlx::FromRow<'r, sqlx::any::AnyRow> + Send + Unpin + 'a,
     {
-        sqlx::query_as_with::<_, T, _>(self.sql, self.args).fetch_one(self.conn.executor()).await
+        Box::pin(async move {
+            Ok(sqlx::query_as_with::<_, T, _>(self.sql, self.args).fetch_one(self.conn.executor()).await?)
+        })
     }
 
     /// Executes the query and returns the first matching row, or None if not found.
-    pub async fn fetch_optional<T>(mut self) -> Result<Option<T>, Error>
+    pub fn fetch_optional<T>(mut self) -> BoxFuture<'a, Result<Option<T>, Error>>
     where
-        T: for<'r> sqlx::FromRow<'r, sqlx::any::AnyRow> + Send + Unpin,
+        C: 'a,
+        T: for<'r> sqlx::FromRow<'r, sqlx::any::AnyRow> + Send + Unpin + 'a,
     {
-        sqlx::query_as_with::<_, T, _>(self.sql, self.args).fetch_optional(self.conn.executor()).await
+        Box::pin(async move {
+            Ok(sqlx::query_as_with::<_, T, _>(self.sql, self.args).fetch_optional(self.conn.executor()).await?)
+        })
     }
 
     /// Executes the query and returns a single scalar value.
     ///
     /// Useful for queries like `SELECT count(*) ...` or `SELECT id ...`.
-    pub async fn fetch_scalar<O>(mut self) -> Result<O, Error>
+    pub fn fetch_scalar<O>(mut self) -> BoxFuture<'a, Result<O, Error>>
     where
-        O: for<'r> sqlx::Decode<'r, sqlx::Any> + sqlx::Type<sqlx::Any> + Send + Unpin,
+        C: 'a,
+        O: for<'r> sqlx::Decode<'r, sqlx::Any> + sqlx::Type<sqlx::Any> + Send + Unpin + 'a,
     {
-        sqlx::query_scalar_with::<_, O, _>(self.sql, self.args).fetch_one(self.conn.executor()).await
+        Box::pin(async move {
+            Ok(sqlx::query_scalar_with::<_, O, _>(self.sql, self.args).fetch_one(self.conn.executor()).await?)
+        })
     }
 
     /// Executes the query and returns a single scalar value, or None if not found.
-    pub async fn fetch_scalar_optional<O>(mut self) -> Result<Option<O>, Error>
+    pub fn fetch_scalar_optional<O>(mut self) -> BoxFuture<'a, Result<Option<O>, Error>>
     where
-        O: for<'r> sqlx::Decode<'r, sqlx::Any> + sqlx::Type<sqlx::Any> + Send + Unpin,
+        C: 'a,
+        O: for<'r> sqlx::Decode<'r, sqlx::Any> + sqlx::Type<sqlx::Any> + Send + Unpin + 'a,
     {
-        sqlx::query_scalar_with::<_, O, _>(self.sql, self.args).fetch_optional(self.conn.executor()).await
+        Box::pin(async move {
+            Ok(sqlx::query_scalar_with::<_, O, _>(self.sql, self.args).fetch_optional(self.conn.executor()).await?)
+        })
     }
 
     /// Executes the query (INSERT, UPDATE, DELETE) and returns the number of affected rows.
-    pub async fn execute(mut self) -> Result<u64, Error> {
-        let result = sqlx::query_with(self.sql, self.args).execute(self.conn.executor()).await?;
+    pub fn execute(mut self) -> BoxFuture<'a, Result<u64, Error>>
+    where
+        C: 'a,
+    {
+        Box::pin(async move {
+            let result = sqlx::query_with(self.sql, self.args).execute(self.conn.executor()).await?;
 
-        Ok(result.rows_affected())
+            Ok(result.rows_affected())
+        })
     }
 }
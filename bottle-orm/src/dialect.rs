@@ -0,0 +1,660 @@
+//! # SQL Dialect Module
+//!
+//! This module defines the `SqlDialect` trait, which centralizes every piece of
+//! SQL syntax that differs between database engines: parameter placeholders,
+//! identifier quoting, DDL type names, LIMIT/OFFSET, RETURNING, upserts, value
+//! encoding, temporal projections and the current-timestamp expression.
+//!
+//! ## Built-in Dialects
+//!
+//! - [`PostgresDialect`] - `$1` placeholders, `"ident"` quoting, `::TYPE` casts
+//! - [`MySqlDialect`] - `?` placeholders, `` `ident` `` quoting, `ON DUPLICATE KEY UPDATE`
+//! - [`SqliteDialect`] - `?` placeholders, `"ident"` quoting, `ON CONFLICT`
+//!
+//! ## Custom Dialects
+//!
+//! Databases that speak the PostgreSQL, MySQL or SQLite wire protocol but differ
+//! in syntax can be supported by implementing `SqlDialect` (usually by delegating
+//! to a built-in dialect) and registering it on the builder:
+//!
+//! ```rust,ignore
+//! use bottle_orm::{database::Drivers, dialect::{PostgresDialect, SqlDialect}, Database};
+//!
+//! #[derive(Debug)]
+//! struct CockroachDialect;
+//!
+//! impl SqlDialect for CockroachDialect {
+//!     fn driver(&self) -> Drivers {
+//!         Drivers::Postgres
+//!     }
+//!     fn placeholder(&self, index: usize) -> String {
+//!         PostgresDialect.placeholder(index)
+//!     }
+//!     fn quote_ident(&self, ident: &str) -> String {
+//!         PostgresDialect.quote_ident(ident)
+//!     }
+//!     fn type_name(&self, sql_type: &str, keyed: bool) -> String {
+//!         match sql_type {
+//!             "JSONB" => "JSONB".to_string(),
+//!             other => PostgresDialect.type_name(other, keyed),
+//!         }
+//!     }
+//!     // ...
+//! }
+//!
+//! let db = Database::builder().dialect(CockroachDialect).connect("postgres://...").await?;
+//! ```
+
+// ============================================================================
+// External Crate Imports
+// ============================================================================
+
+use sqlx::any::AnyArguments;
+use sqlx_core::any::AnyValueKind;
+use std::{fmt::Debug, sync::Arc};
+
+// ============================================================================
+// Internal Crate Imports
+// ============================================================================
+
+use crate::{
+    database::Drivers,
+    query_builder::{Direction, LockMode, LockWait, Nulls},
+    transaction::TxOptions,
+    temporal,
+    value_binding::ValueBinder,
+    Error,
+};
+
+// ============================================================================
+// SqlDialect Trait
+// ============================================================================
+
+/// SQL syntax rules for one database engine.
+///
+/// Every statement generated by `Database`, `QueryBuilder` and `Migrator` goes
+/// through the dialect, so implementing this trait is enough to support a new
+/// engine that is wire-compatible with one of the [`Drivers`].
+///
+/// # Required Methods
+///
+/// * `driver()` - Wire protocol family used for connections and value binding
+/// * `placeholder()` - Bind parameter syntax
+/// * `quote_ident()` - Identifier quoting
+/// * `type_name()` - Logical type to DDL type mapping
+/// * `limit_offset()` - Row limiting clause
+/// * `supports_returning()` - Whether `RETURNING` is available
+/// * `upsert()` - Conflict handling clause for `INSERT`
+/// * `now()` - Current timestamp expression
+pub trait SqlDialect: Debug + Send + Sync {
+    /// Returns the driver family this dialect is wire-compatible with.
+    ///
+    /// Connections and engine-specific features (advisory locks, identity
+    /// columns, index methods) are selected by this family, and `bind_value()`
+    /// encodes values the way it expects by default.
+    fn driver(&self) -> Drivers;
+
+    /// Returns the bind parameter placeholder for the 1-based `index`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// assert_eq!(PostgresDialect.placeholder(2), "$2");
+    /// assert_eq!(MySqlDialect.placeholder(2), "?");
+    /// ```
+    fn placeholder(&self, index: usize) -> String;
+
+    /// Quotes an SQL identifier (table, column, index or constraint name).
+    ///
+    /// Implementations must escape embedded quote characters.
+    fn quote_ident(&self, ident: &str) -> String;
+
+    /// Quotes a column reference that may be qualified with a table name.
+    ///
+    /// `"table.column"` is quoted as two identifiers; a bare name is quoted as one.
+    fn quote_column(&self, col: &str) -> String {
+        match col.split_once('.') {
+            Some((table, column)) => format!("{}.{}", self.quote_ident(table), self.quote_ident(column)),
+            None => self.quote_ident(col),
+        }
+    }
+
+    /// Translates a logical column type (as stored in `ColumnInfo::sql_type`) to the DDL type.
+    ///
+    /// # Arguments
+    ///
+    /// * `sql_type` - The logical type (e.g. `"UUID"`, `"TIMESTAMPTZ"`, `"TEXT"`)
+    /// * `keyed` - Whether the column is part of a key or index
+    fn type_name(&self, sql_type: &str, keyed: bool) -> String;
+
+    /// Wraps a placeholder with the cast required to bind a value of `sql_type`.
+    ///
+    /// The default implementation returns the placeholder unchanged.
+    fn cast_placeholder(&self, placeholder: String, sql_type: &str) -> String {
+        let _ = sql_type;
+        placeholder
+    }
+
+    /// Binds a model value, given in its string form, as a parameter of logical type `sql_type`.
+    ///
+    /// The default implementation encodes it the way the `driver()` family expects
+    /// (see `ValueBinder::bind_value`): numbers and booleans natively, UUIDs and
+    /// temporal values as text in the family's format (e.g. `YYYY-MM-DD HH:MM:SS.ffffff`
+    /// for MySQL timestamps). Override it together with `cast_placeholder()` to
+    /// encode values differently.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The value was bound
+    /// * `Err(Error)` - The value could not be parsed as `sql_type`; callers bind it as text
+    fn bind_value(&self, args: &mut AnyArguments<'_>, value: &str, sql_type: &str) -> Result<(), Error> {
+        args.bind_value(value, sql_type, &self.driver())
+    }
+
+    /// Renders the SELECT entry for a temporal column under `alias`.
+    ///
+    /// `column` and `alias` are already quoted. The result must decode as text
+    /// through the `Any` driver. The default implementation selects the column as-is.
+    fn select_temporal(&self, column: &str, alias: &str) -> String {
+        format!("{} AS {}", column, alias)
+    }
+
+    /// Renders the row limiting clause, including its leading space.
+    ///
+    /// `limit` and `offset` are SQL fragments (usually placeholders). Returns an
+    /// empty string when both are `None`.
+    fn limit_offset(&self, limit: Option<&str>, offset: Option<&str>) -> String;
+
+    /// Whether `INSERT`/`UPDATE`/`DELETE ... RETURNING` is supported.
+    fn supports_returning(&self) -> bool;
+
+    /// Renders a `RETURNING` clause for the given columns, including its leading space.
+    ///
+    /// Returns `None` if the dialect does not support `RETURNING`.
+    fn returning(&self, columns: &[&str]) -> Option<String> {
+        if !self.supports_returning() {
+            return None;
+        }
+        let columns: Vec<String> = columns.iter().map(|c| self.quote_ident(c)).collect();
+        Some(format!(" RETURNING {}", columns.join(", ")))
+    }
+
+    /// Renders the conflict clause appended to an `INSERT` to turn it into an upsert.
+    ///
+    /// # Arguments
+    ///
+    /// * `conflict_columns` - Columns of the unique key that identifies a conflict
+    /// * `update_columns` - Columns overwritten with the new values on conflict;
+    ///   when empty, conflicting rows are left untouched
+    fn upsert(&self, conflict_columns: &[&str], update_columns: &[&str]) -> String;
+
+    /// Whether an upsert counts a conflicting row it left untouched as affected.
+    ///
+    /// When it does, `find_or_create()` cannot tell from the affected-row count
+    /// whether it inserted, and uses a transaction instead. The default
+    /// implementation returns `false`.
+    fn upsert_reports_skipped_rows(&self) -> bool {
+        false
+    }
+
+    /// Returns the SQL expression for the current timestamp.
+    fn now(&self) -> &'static str;
+
+    /// Renders the clause that makes a column generated from `expr`, including its leading space.
+    ///
+    /// It is placed directly after the column type. The default implementation
+    /// emits `GENERATED ALWAYS AS (expr) STORED` or `... VIRTUAL` (MySQL, SQLite).
+    fn generated_column(&self, expr: &str, stored: bool) -> String {
+        let storage = if stored { "STORED" } else { "VIRTUAL" };
+        format!(" GENERATED ALWAYS AS ({}) {}", expr, storage)
+    }
+
+    /// Renders one ORDER BY entry for an already quoted column.
+    ///
+    /// The default implementation uses the standard `NULLS FIRST` / `NULLS LAST`
+    /// modifiers, supported by PostgreSQL and SQLite 3.30+.
+    fn order_by(&self, column: &str, direction: Direction, nulls: Option<Nulls>) -> String {
+        match nulls {
+            Some(Nulls::First) => format!("{} {} NULLS FIRST", column, direction.as_sql()),
+            Some(Nulls::Last) => format!("{} {} NULLS LAST", column, direction.as_sql()),
+            None => format!("{} {}", column, direction.as_sql()),
+        }
+    }
+
+    /// Renders the row locking clause appended to a SELECT.
+    ///
+    /// The default implementation uses `FOR UPDATE` / `FOR SHARE` with the
+    /// `NOWAIT` and `SKIP LOCKED` modifiers (PostgreSQL 9.5+, MySQL 8.0+).
+    fn lock_clause(&self, mode: LockMode, wait: LockWait) -> String {
+        let mode = match mode {
+            LockMode::Update => " FOR UPDATE",
+            LockMode::Share => " FOR SHARE",
+        };
+        match wait {
+            LockWait::Wait => mode.to_string(),
+            LockWait::NoWait => format!("{} NOWAIT", mode),
+            LockWait::SkipLocked => format!("{} SKIP LOCKED", mode),
+        }
+    }
+
+    /// Returns a statement that emulates row locks on engines without a locking clause.
+    ///
+    /// It runs inside the transaction before a locking SELECT on `table` (already
+    /// quoted). The default implementation returns `None`.
+    fn lock_emulation(&self, table: &str) -> Option<String> {
+        let _ = table;
+        None
+    }
+
+    /// Returns the statements that open a transaction with `options`.
+    ///
+    /// The last statement starts the transaction; any preceding ones run on the
+    /// connection just before it. The default implementation uses PostgreSQL's
+    /// `BEGIN ISOLATION LEVEL ..., READ ONLY, DEFERRABLE` form.
+    fn begin_statements(&self, options: &TxOptions) -> Vec<String> {
+        let mut modes = Vec::new();
+        if let Some(isolation) = options.isolation {
+            modes.push(format!("ISOLATION LEVEL {}", isolation.as_sql()));
+        }
+        if options.read_only {
+            modes.push("READ ONLY".to_string());
+        }
+        if options.deferrable {
+            modes.push("DEFERRABLE".to_string());
+        }
+
+        if modes.is_empty() {
+            return vec!["BEGIN".to_string()];
+        }
+        vec![format!("BEGIN {}", modes.join(", "))]
+    }
+
+    /// Returns `true` if transactions can be opened in read-only mode.
+    ///
+    /// `Database::begin_with` rejects `TxOptions::read_only` with
+    /// `Error::InvalidArgument` when this is `false`, rather than opening a
+    /// writable transaction. The default implementation returns `true`.
+    fn supports_read_only_transactions(&self) -> bool {
+        true
+    }
+
+    /// Returns `true` if schema changes (`CREATE TABLE`, `ALTER TABLE`) can be rolled back.
+    ///
+    /// `Migrator::transactional()` only wraps the migration in one transaction
+    /// when this holds. The default implementation returns `true`.
+    fn supports_transactional_ddl(&self) -> bool {
+        true
+    }
+
+    /// Quotes a string as an SQL literal.
+    ///
+    /// The default implementation doubles embedded single quotes.
+    fn quote_literal(&self, value: &str) -> String {
+        format!("'{}'", value.replace('\'', "''"))
+    }
+
+    /// Renders binary data as an SQL literal (`X'DEADBEEF'` by default).
+    fn blob_literal(&self, bytes: &[u8]) -> String {
+        let hex: String = bytes.iter().map(|b| format!("{:02X}", b)).collect();
+        format!("X'{}'", hex)
+    }
+
+    /// Renders a bound value as an SQL literal.
+    ///
+    /// Used for debug output only (`to_sql_with_params()`, `interpolated_sql()`);
+    /// queries sent to the database always use bound parameters.
+    fn literal(&self, value: &AnyValueKind<'_>) -> String {
+        match value {
+            AnyValueKind::Null(_) => "NULL".to_string(),
+            AnyValueKind::Bool(b) => if *b { "TRUE" } else { "FALSE" }.to_string(),
+            AnyValueKind::SmallInt(v) => v.to_string(),
+            AnyValueKind::Integer(v) => v.to_string(),
+            AnyValueKind::BigInt(v) => v.to_string(),
+            AnyValueKind::Real(v) => v.to_string(),
+            AnyValueKind::Double(v) => v.to_string(),
+            AnyValueKind::Text(v) => self.quote_literal(v),
+            AnyValueKind::Blob(v) => self.blob_literal(v),
+            _ => "NULL".to_string(),
+        }
+    }
+
+    /// Replaces the placeholders of `sql` with the given literals, in order.
+    ///
+    /// Recognizes placeholders outside of quoted strings and identifiers: `?`
+    /// when `placeholder(1)` is `?`, and `$n` otherwise, so a `?` operator (such
+    /// as PostgreSQL's jsonb key test) is kept. Placeholders without a matching
+    /// literal are left as-is.
+    fn interpolate(&self, sql: &str, literals: &[String]) -> String {
+        let mut out = String::with_capacity(sql.len());
+        let mut chars = sql.chars().peekable();
+        let mut next = 0;
+        let positional = self.placeholder(1) == "?";
+
+        while let Some(c) = chars.next() {
+            match c {
+                // Copy quoted strings and identifiers verbatim
+                '\'' | '"' | '`' => {
+                    out.push(c);
+                    for inner in chars.by_ref() {
+                        out.push(inner);
+                        if inner == c {
+                            break;
+                        }
+                    }
+                }
+                '?' if positional => match literals.get(next) {
+                    Some(literal) => {
+                        out.push_str(literal);
+                        next += 1;
+                    }
+                    None => out.push(c),
+                },
+                '$' if !positional && chars.peek().is_some_and(|d| d.is_ascii_digit()) => {
+                    let mut digits = String::new();
+                    while let Some(d) = chars.peek().filter(|d| d.is_ascii_digit()) {
+                        digits.push(*d);
+                        chars.next();
+                    }
+                    match digits.parse::<usize>().ok().and_then(|n| literals.get(n.wrapping_sub(1))) {
+                        Some(literal) => out.push_str(literal),
+                        None => {
+                            out.push('$');
+                            out.push_str(&digits);
+                        }
+                    }
+                }
+                _ => out.push(c),
+            }
+        }
+
+        out
+    }
+}
+
+// ============================================================================
+// Built-in Dialects
+// ============================================================================
+
+/// Renders `ON CONFLICT (...) DO UPDATE SET ...` (PostgreSQL and SQLite).
+fn on_conflict_upsert(dialect: &dyn SqlDialect, conflict_columns: &[&str], update_columns: &[&str]) -> String {
+    let target: Vec<String> = conflict_columns.iter().map(|c| dialect.quote_ident(c)).collect();
+
+    if update_columns.is_empty() {
+        return format!(" ON CONFLICT ({}) DO NOTHING", target.join(", "));
+    }
+
+    let sets: Vec<String> = update_columns
+        .iter()
+        .map(|c| {
+            let col = dialect.quote_ident(c);
+            format!("{} = EXCLUDED.{}", col, col)
+        })
+        .collect();
+    format!(" ON CONFLICT ({}) DO UPDATE SET {}", target.join(", "), sets.join(", "))
+}
+
+/// PostgreSQL dialect.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PostgresDialect;
+
+impl SqlDialect for PostgresDialect {
+    fn driver(&self) -> Drivers {
+        Drivers::Postgres
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        format!("${}", index)
+    }
+
+    fn quote_ident(&self, ident: &str) -> String {
+        format!("\"{}\"", ident.replace('"', "\"\""))
+    }
+
+    fn type_name(&self, sql_type: &str, _keyed: bool) -> String {
+        sql_type.to_string()
+    }
+
+    fn cast_placeholder(&self, placeholder: String, sql_type: &str) -> String {
+        // PostgreSQL requires explicit casts for values bound as text
+        if temporal::is_temporal_type(sql_type) {
+            return format!("{}{}", placeholder, temporal::get_postgres_type_cast(sql_type));
+        }
+        match sql_type {
+            "UUID" => format!("{}::UUID", placeholder),
+            "JSONB" | "jsonb" => format!("{}::JSONB", placeholder),
+            _ => placeholder,
+        }
+    }
+
+    fn select_temporal(&self, column: &str, alias: &str) -> String {
+        // Temporal types have no `Any` mapping; render them as text
+        format!("to_json({}) #>> '{{}}' AS {}", column, alias)
+    }
+
+    fn limit_offset(&self, limit: Option<&str>, offset: Option<&str>) -> String {
+        let mut clause = String::new();
+        if let Some(limit) = limit {
+            clause.push_str(&format!(" LIMIT {}", limit));
+        }
+        if let Some(offset) = offset {
+            clause.push_str(&format!(" OFFSET {}", offset));
+        }
+        clause
+    }
+
+    fn supports_returning(&self) -> bool {
+        true
+    }
+
+    fn upsert(&self, conflict_columns: &[&str], update_columns: &[&str]) -> String {
+        on_conflict_upsert(self, conflict_columns, update_columns)
+    }
+
+    fn now(&self) -> &'static str {
+        "NOW()"
+    }
+
+    /// PostgreSQL before version 18 only supports stored generated columns.
+    fn generated_column(&self, expr: &str, _stored: bool) -> String {
+        format!(" GENERATED ALWAYS AS ({}) STORED", expr)
+    }
+
+    fn blob_literal(&self, bytes: &[u8]) -> String {
+        let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        format!("'\\x{}'::bytea", hex)
+    }
+}
+
+/// MySQL / MariaDB dialect.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MySqlDialect;
+
+impl SqlDialect for MySqlDialect {
+    fn driver(&self) -> Drivers {
+        Drivers::MySQL
+    }
+
+    fn placeholder(&self, _index: usize) -> String {
+        "?".to_string()
+    }
+
+    fn quote_ident(&self, ident: &str) -> String {
+        format!("`{}`", ident.replace('`', "``"))
+    }
+
+    fn type_name(&self, sql_type: &str, keyed: bool) -> String {
+        match sql_type {
+            "UUID" => "CHAR(36)",
+            "JSON" | "JSONB" | "jsonb" => "JSON",
+            "TIMESTAMPTZ" | "TIMESTAMP" => "DATETIME(6)",
+            "DOUBLE PRECISION" => "DOUBLE",
+            // MySQL cannot index an unsized TEXT column
+            "TEXT" if keyed => "VARCHAR(255)",
+            other => other,
+        }
+        .to_string()
+    }
+
+    fn limit_offset(&self, limit: Option<&str>, offset: Option<&str>) -> String {
+        match (limit, offset) {
+            (Some(limit), Some(offset)) => format!(" LIMIT {} OFFSET {}", limit, offset),
+            (Some(limit), None) => format!(" LIMIT {}", limit),
+            // MySQL has no OFFSET without LIMIT; use the largest row count
+            (None, Some(offset)) => format!(" LIMIT 18446744073709551615 OFFSET {}", offset),
+            (None, None) => String::new(),
+        }
+    }
+
+    fn supports_returning(&self) -> bool {
+        false
+    }
+
+    fn upsert(&self, conflict_columns: &[&str], update_columns: &[&str]) -> String {
+        // MySQL infers the conflict target from the table's unique keys
+        let sets: Vec<String> = if update_columns.is_empty() {
+            // No-op assignment so conflicting rows are left untouched
+            conflict_columns.iter().take(1).map(|c| format!("{0} = {0}", self.quote_ident(c))).collect()
+        } else {
+            update_columns
+                .iter()
+                .map(|c| {
+                    let col = self.quote_ident(c);
+                    format!("{} = VALUES({})", col, col)
+                })
+                .collect()
+        };
+        format!(" ON DUPLICATE KEY UPDATE {}", sets.join(", "))
+    }
+
+    fn upsert_reports_skipped_rows(&self) -> bool {
+        // sqlx sets CLIENT_FOUND_ROWS, so matched rows count even when unchanged
+        true
+    }
+
+    fn now(&self) -> &'static str {
+        "NOW()"
+    }
+
+    fn order_by(&self, column: &str, direction: Direction, nulls: Option<Nulls>) -> String {
+        // MySQL has no NULLS FIRST/LAST; sort on `col IS NULL` (0 for values, 1 for NULL) first
+        match nulls {
+            Some(Nulls::First) => format!("{} IS NULL DESC, {} {}", column, column, direction.as_sql()),
+            Some(Nulls::Last) => format!("{} IS NULL ASC, {} {}", column, column, direction.as_sql()),
+            None => format!("{} {}", column, direction.as_sql()),
+        }
+    }
+
+    fn begin_statements(&self, options: &TxOptions) -> Vec<String> {
+        // The isolation level applies to the next transaction only when set before it starts
+        let mut statements = Vec::new();
+        if let Some(isolation) = options.isolation {
+            statements.push(format!("SET TRANSACTION ISOLATION LEVEL {}", isolation.as_sql()));
+        }
+        let begin = if options.read_only { "START TRANSACTION READ ONLY" } else { "START TRANSACTION" };
+        statements.push(begin.to_string());
+        statements
+    }
+
+    fn supports_transactional_ddl(&self) -> bool {
+        // DDL statements commit the current transaction implicitly
+        false
+    }
+
+    fn quote_literal(&self, value: &str) -> String {
+        // Backslash is an escape character in MySQL string literals by default
+        format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''"))
+    }
+}
+
+/// SQLite dialect.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SqliteDialect;
+
+impl SqlDialect for SqliteDialect {
+    fn driver(&self) -> Drivers {
+        Drivers::SQLite
+    }
+
+    fn placeholder(&self, _index: usize) -> String {
+        "?".to_string()
+    }
+
+    fn quote_ident(&self, ident: &str) -> String {
+        format!("\"{}\"", ident.replace('"', "\"\""))
+    }
+
+    fn type_name(&self, sql_type: &str, _keyed: bool) -> String {
+        match sql_type {
+            // Stored as text so values keep TEXT affinity
+            "UUID" | "JSON" | "JSONB" | "jsonb" | "TIMESTAMPTZ" | "TIMESTAMP" | "DATE" | "TIME" => "TEXT",
+            "DOUBLE PRECISION" => "REAL",
+            other => other,
+        }
+        .to_string()
+    }
+
+    fn limit_offset(&self, limit: Option<&str>, offset: Option<&str>) -> String {
+        match (limit, offset) {
+            (Some(limit), Some(offset)) => format!(" LIMIT {} OFFSET {}", limit, offset),
+            (Some(limit), None) => format!(" LIMIT {}", limit),
+            // SQLite requires LIMIT before OFFSET; -1 means no limit
+            (None, Some(offset)) => format!(" LIMIT -1 OFFSET {}", offset),
+            (None, None) => String::new(),
+        }
+    }
+
+    fn supports_returning(&self) -> bool {
+        true
+    }
+
+    fn upsert(&self, conflict_columns: &[&str], update_columns: &[&str]) -> String {
+        on_conflict_upsert(self, conflict_columns, update_columns)
+    }
+
+    fn now(&self) -> &'static str {
+        "strftime('%Y-%m-%dT%H:%M:%SZ', 'now')"
+    }
+
+    fn begin_statements(&self, _options: &TxOptions) -> Vec<String> {
+        // SQLite transactions are always serializable
+        vec!["BEGIN".to_string()]
+    }
+
+    fn supports_read_only_transactions(&self) -> bool {
+        // `PRAGMA query_only` is per connection and would outlive the transaction
+        false
+    }
+
+    fn lock_clause(&self, _mode: LockMode, _wait: LockWait) -> String {
+        // SQLite locks the whole database rather than rows; see `lock_emulation`
+        String::new()
+    }
+
+    fn lock_emulation(&self, table: &str) -> Option<String> {
+        // A write that matches no rows takes the RESERVED lock, like `BEGIN IMMEDIATE`,
+        // so other writers wait until this transaction ends
+        Some(format!("DELETE FROM {} WHERE 0", table))
+    }
+}
+
+// ============================================================================
+// Driver Defaults
+// ============================================================================
+
+impl Drivers {
+    /// Returns the built-in dialect for this driver.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let dialect = Drivers::MySQL.dialect();
+    /// assert_eq!(dialect.quote_ident("user"), "`user`");
+    /// ```
+    pub fn dialect(&self) -> Arc<dyn SqlDialect> {
+        match self {
+            Drivers::Postgres => Arc::new(PostgresDialect),
+            Drivers::MySQL => Arc::new(MySqlDialect),
+            Drivers::SQLite => Arc::new(SqliteDialect),
+        }
+    }
+}
@@ -9,6 +9,14 @@
 //! - **InvalidData**: Data validation errors (e.g., invalid format, constraint violations)
 //! - **DatabaseError**: Wrapped sqlx errors (connection issues, query failures, etc.)
 //! - **InvalidArgument**: Invalid arguments passed to ORM methods
+//! - **UniqueViolation**, **ForeignKeyViolation**, **NotNullViolation**, **CheckViolation**:
+//!   Constraint violations reported by the database
+//! - **NotFound**: A query expected a row but found none
+//! - **Deadlock**, **Timeout**: Concurrency failures that may succeed when retried
+//!
+//! Driver errors are classified when they are converted from `sqlx::Error`, using
+//! the SQLSTATE (PostgreSQL), the error number (MySQL) or the extended result code
+//! (SQLite). The original `sqlx::Error` stays available as the error's `source()`.
 //!
 //! ## Example Usage
 //!
@@ -29,6 +37,7 @@
 //! match create_user(&db, -5).await {
 //!     Ok(user) => println!("Created: {:?}", user),
 //!     Err(Error::InvalidData(msg)) => eprintln!("Validation error: {}", msg),
+//!     Err(Error::UniqueViolation { columns, .. }) => eprintln!("Already taken: {:?}", columns),
 //!     Err(Error::DatabaseError(e)) => eprintln!("Database error: {}", e),
 //!     Err(e) => eprintln!("Other error: {}", e),
 //! }
@@ -38,6 +47,7 @@
 // External Crate Imports
 // ============================================================================
 
+use sqlx::error::DatabaseError;
 use thiserror::Error;
 
 // ============================================================================
@@ -55,6 +65,9 @@ use thiserror::Error;
 /// * `InvalidData` - Data validation errors
 /// * `DatabaseError` - Wrapped sqlx database errors
 /// * `InvalidArgument` - Invalid arguments passed to methods
+/// * `UniqueViolation` / `ForeignKeyViolation` / `NotNullViolation` / `CheckViolation` - Constraint violations
+/// * `NotFound` - No row matched a query that requires one
+/// * `Deadlock` / `Timeout` - Concurrency failures
 ///
 /// # Display Format
 ///
@@ -133,25 +146,22 @@ pub enum Error {
 
     /// Database operation error.
     ///
-    /// This variant wraps errors from the underlying sqlx library.
-    /// It's automatically converted from `sqlx::Error` via the `#[from]` attribute,
-    /// making error propagation seamless with the `?` operator.
+    /// This variant wraps errors from the underlying sqlx library that have no
+    /// more specific variant. `sqlx::Error` converts into `Error` with the `?`
+    /// operator, which picks the matching variant (see the `From` implementation).
     ///
     /// # Common Causes
     ///
-    /// - **Connection Errors**: Failed to connect to database, connection pool exhausted
+    /// - **Connection Errors**: Failed to connect to database
     /// - **Query Errors**: SQL syntax errors, table/column not found
-    /// - **Constraint Violations**: Primary key, foreign key, unique, not null violations
     /// - **Type Errors**: Type mismatch between Rust and SQL types
-    /// - **Row Not Found**: `fetch_one()` or `first()` found no matching rows
     ///
     /// # Example
     ///
     /// ```rust,ignore
     /// async fn get_user_by_id(db: &Database, id: i32) -> Result<User, Error> {
-    ///     // sqlx::Error is automatically converted to Error::DatabaseError
     ///     let user = db.model::<User>()
-    ///         .filter("id", "=", id)
+    ///         .filter("id", Op::Eq, id)
     ///         .first()
     ///         .await?;
     ///     Ok(user)
@@ -160,18 +170,93 @@ pub enum Error {
     /// // Handling specific database errors
     /// match get_user_by_id(&db, 999).await {
     ///     Ok(user) => println!("Found: {:?}", user),
-    ///     Err(Error::DatabaseError(e)) => {
-    ///         if matches!(e, sqlx::Error::RowNotFound) {
-    ///             eprintln!("User not found");
-    ///         } else {
-    ///             eprintln!("Database error: {}", e);
-    ///         }
-    ///     }
+    ///     Err(Error::NotFound) => eprintln!("User not found"),
+    ///     Err(Error::DatabaseError(e)) => eprintln!("Database error: {}", e),
     ///     Err(e) => eprintln!("Other error: {}", e),
     /// }
     /// ```
     #[error("Database error {0}:")]
-    DatabaseError(#[from] sqlx::Error),
+    DatabaseError(#[source] sqlx::Error),
+
+    /// A `UNIQUE` or `PRIMARY KEY` constraint was violated.
+    ///
+    /// `constraint` is the violated constraint or index name (PostgreSQL, MySQL)
+    /// and `columns` the affected columns (PostgreSQL, SQLite), when the driver
+    /// reports them.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// match db.model::<User>().insert(&user).await {
+    ///     Err(Error::UniqueViolation { columns, .. }) if columns == ["email"] => {
+    ///         return Err(ApiError::Conflict("email already registered"));
+    ///     }
+    ///     result => result?,
+    /// }
+    /// ```
+    #[error("Unique constraint violation: {source}")]
+    UniqueViolation {
+        /// Name of the violated constraint or index
+        constraint: Option<String>,
+        /// Columns covered by the constraint
+        columns: Vec<String>,
+        /// The driver error
+        source: sqlx::Error,
+    },
+
+    /// A `FOREIGN KEY` constraint was violated.
+    ///
+    /// `constraint` is reported by PostgreSQL and MySQL; SQLite does not name it.
+    #[error("Foreign key violation: {source}")]
+    ForeignKeyViolation {
+        /// Name of the violated constraint
+        constraint: Option<String>,
+        /// The driver error
+        source: sqlx::Error,
+    },
+
+    /// A `NULL` was written to a `NOT NULL` column.
+    #[error("Not null violation: {source}")]
+    NotNullViolation {
+        /// The column that rejected the `NULL`
+        column: Option<String>,
+        /// The driver error
+        source: sqlx::Error,
+    },
+
+    /// A `CHECK` constraint was violated.
+    ///
+    /// On SQLite, `constraint` holds the constraint name or, for unnamed
+    /// constraints, its expression.
+    #[error("Check constraint violation: {source}")]
+    CheckViolation {
+        /// Name of the violated constraint
+        constraint: Option<String>,
+        /// The driver error
+        source: sqlx::Error,
+    },
+
+    /// A query that requires a row found none.
+    ///
+    /// Returned by `first()` and `RawQuery::fetch_one()` instead of `sqlx::Error::RowNotFound`.
+    #[error("Row not found")]
+    NotFound,
+
+    /// The transaction was aborted by a deadlock or a serialization failure.
+    ///
+    /// Covers SQLSTATE `40P01` / `40001` (PostgreSQL), error 1213 (MySQL) and
+    /// `SQLITE_BUSY_SNAPSHOT`. Retrying the transaction may succeed, see
+    /// `Database::transaction_with_retry()`.
+    #[error("Deadlock or serialization failure: {0}")]
+    Deadlock(#[source] sqlx::Error),
+
+    /// An operation gave up waiting.
+    ///
+    /// Covers pool acquire timeouts, lock wait timeouts (SQLSTATE `55P03`, MySQL
+    /// 1205, `SQLITE_BUSY`), cancelled statements (SQLSTATE `57014`, MySQL 3024)
+    /// and the migration lock timeout.
+    #[error("Timeout: {0}")]
+    Timeout(#[source] sqlx::Error),
 
     /// Invalid argument error.
     ///
@@ -217,21 +302,147 @@ pub enum Error {
 // Error Conversion Implementations
 // ============================================================================
 
-/// Automatic conversion from `sqlx::Error` to `Error::DatabaseError`.
+/// Classifies a `sqlx::Error` into the matching `Error` variant.
 ///
-/// This is provided automatically by the `#[from]` attribute on the
-/// `DatabaseError` variant. It enables using the `?` operator to propagate
-/// sqlx errors as Bottle ORM errors.
+/// This enables using the `?` operator to propagate sqlx errors as Bottle ORM
+/// errors. Errors without a specific variant become `Error::DatabaseError`.
 ///
 /// # Example
 ///
 /// ```rust,ignore
 /// async fn example(db: &Database) -> Result<Vec<User>, Error> {
 ///     // sqlx::Error is automatically converted to Error via ?
-///     let users = db.model::<User>().scan().await?;
-///     Ok(users)
+///     let rows = sqlx::query_as("SELECT * FROM \"user\"").fetch_all(pool).await?;
+///     Ok(rows)
 /// }
 /// ```
+impl From<sqlx::Error> for Error {
+    fn from(error: sqlx::Error) -> Self {
+        let category = match &error {
+            sqlx::Error::RowNotFound => return Error::NotFound,
+            sqlx::Error::PoolTimedOut => Category::Timeout,
+            sqlx::Error::Io(e) if e.kind() == std::io::ErrorKind::TimedOut => Category::Timeout,
+            sqlx::Error::Database(db_error) => Category::of(db_error.as_ref()),
+            _ => Category::Other,
+        };
+
+        match category {
+            Category::Unique { constraint, columns } => Error::UniqueViolation { constraint, columns, source: error },
+            Category::ForeignKey { constraint } => Error::ForeignKeyViolation { constraint, source: error },
+            Category::NotNull { column } => Error::NotNullViolation { column, source: error },
+            Category::Check { constraint } => Error::CheckViolation { constraint, source: error },
+            Category::Deadlock => Error::Deadlock(error),
+            Category::Timeout => Error::Timeout(error),
+            Category::Other => Error::DatabaseError(error),
+        }
+    }
+}
+
+// ============================================================================
+// Driver Error Classification
+// ============================================================================
+
+/// The kind of a driver error, with the details its message reveals.
+enum Category {
+    Unique { constraint: Option<String>, columns: Vec<String> },
+    ForeignKey { constraint: Option<String> },
+    NotNull { column: Option<String> },
+    Check { constraint: Option<String> },
+    Deadlock,
+    Timeout,
+    Other,
+}
+
+impl Category {
+    /// Classifies a database error by the driver that produced it.
+    fn of(error: &dyn DatabaseError) -> Self {
+        if let Some(pg) = error.try_downcast_ref::<sqlx::postgres::PgDatabaseError>() {
+            return Self::postgres(pg);
+        }
+        if let Some(mysql) = error.try_downcast_ref::<sqlx::mysql::MySqlDatabaseError>() {
+            return Self::mysql(mysql.number(), mysql.message());
+        }
+        if let Some(sqlite) = error.try_downcast_ref::<sqlx::sqlite::SqliteError>() {
+            let code = sqlite.code().and_then(|c| c.parse::<i32>().ok()).unwrap_or_default();
+            return Self::sqlite(code, sqlite.message());
+        }
+        Category::Other
+    }
+
+    /// Classifies a PostgreSQL error by SQLSTATE.
+    fn postgres(error: &sqlx::postgres::PgDatabaseError) -> Self {
+        let constraint = error.constraint().map(str::to_string);
+        match error.code() {
+            // Detail reads `Key (a, b)=(1, 2) already exists.`
+            "23505" => Category::Unique { constraint, columns: error.detail().map(key_columns).unwrap_or_default() },
+            "23503" => Category::ForeignKey { constraint },
+            "23502" => Category::NotNull { column: error.column().map(str::to_string) },
+            "23514" => Category::Check { constraint },
+            "40P01" | "40001" => Category::Deadlock,
+            // lock_not_available, query_canceled (statement_timeout)
+            "55P03" | "57014" => Category::Timeout,
+            _ => Category::Other,
+        }
+    }
+
+    /// Classifies a MySQL error by error number.
+    fn mysql(number: u16, message: &str) -> Self {
+        match number {
+            // Duplicate entry '1' for key 'user.PRIMARY'
+            1062 | 1586 => {
+                let constraint = quoted(message, "for key '", '\'').map(|key| unqualified(&key));
+                Category::Unique { constraint, columns: Vec::new() }
+            }
+            // ... CONSTRAINT `fk_post_user_id` FOREIGN KEY ...
+            1216 | 1217 | 1451 | 1452 => Category::ForeignKey { constraint: quoted(message, "CONSTRAINT `", '`') },
+            // Column 'name' cannot be null / Field 'name' doesn't have a default value
+            1048 | 1364 => Category::NotNull { column: quoted(message, "'", '\'') },
+            // Check constraint 'age_positive' is violated.
+            3819 => Category::Check { constraint: quoted(message, "'", '\'') },
+            1213 => Category::Deadlock,
+            // Lock wait timeout, maximum statement execution time exceeded
+            1205 | 3024 => Category::Timeout,
+            _ => Category::Other,
+        }
+    }
+
+    /// Classifies a SQLite error by extended result code.
+    fn sqlite(code: i32, message: &str) -> Self {
+        let detail = message.split_once("constraint failed: ").map(|(_, detail)| detail);
+        match code {
+            // UNIQUE constraint failed: user.email, user.name
+            1555 | 2067 => Category::Unique {
+                constraint: None,
+                columns: detail.map(|d| d.split(", ").map(unqualified).collect()).unwrap_or_default(),
+            },
+            787 => Category::ForeignKey { constraint: None },
+            1299 => Category::NotNull { column: detail.map(unqualified) },
+            275 => Category::Check { constraint: detail.map(str::to_string) },
+            // SQLITE_BUSY_SNAPSHOT: another connection committed since this transaction's snapshot
+            517 => Category::Deadlock,
+            // SQLITE_BUSY and SQLITE_LOCKED: busy_timeout expired
+            c if c & 0xff == 5 || c & 0xff == 6 => Category::Timeout,
+            _ => Category::Other,
+        }
+    }
+}
+
+/// Extracts the column list from a PostgreSQL `Key (a, b)=(...)` detail message.
+fn key_columns(detail: &str) -> Vec<String> {
+    let columns = quoted(detail, "Key (", ')').unwrap_or_default();
+    columns.split(", ").filter(|c| !c.is_empty()).map(|c| c.trim_matches('"').to_string()).collect()
+}
+
+/// Returns the text between `prefix` and the next `end` character.
+fn quoted(message: &str, prefix: &str, end: char) -> Option<String> {
+    let (_, rest) = message.split_once(prefix)?;
+    rest.split_once(end).map(|(value, _)| value.to_string())
+}
+
+/// Strips the table from a `table.column` reference.
+fn unqualified(column: &str) -> String {
+    column.rsplit('.').next().unwrap_or(column).to_string()
+}
 
 // ============================================================================
 // Helper Functions and Traits
@@ -63,6 +63,12 @@ pub use bottle_orm_macro::Model;
 /// Rust structs, handling necessary type conversions (especially for temporal types).
 pub use bottle_orm_macro::FromAnyRow;
 
+/// Re-export of the procedural macro for embedding SQL migration files.
+///
+/// Expands to a `&'static [SqlMigration]` built from `V<version>__<name>.up.sql`
+/// and `.down.sql` files, to be registered with `Migrator::register_sql()`.
+pub use bottle_orm_macro::embed_migrations;
+
 // ============================================================================
 // Module Declarations
 // ============================================================================
@@ -73,6 +79,12 @@ pub use bottle_orm_macro::FromAnyRow;
 /// as well as the `Drivers` enum for identifying the database backend.
 pub mod database;
 
+/// SQL dialect abstraction.
+///
+/// Defines the `SqlDialect` trait (placeholders, quoting, type names, LIMIT/OFFSET,
+/// RETURNING, upserts) and the built-in PostgreSQL, MySQL and SQLite dialects.
+pub mod dialect;
+
 /// Core Model trait and column metadata structures.
 ///
 /// Defines the `Model` trait that must be implemented by all ORM entities,
@@ -91,6 +103,12 @@ pub mod any_struct;
 /// SELECT, INSERT, and filtered queries with type-safe parameter binding.
 pub mod query_builder;
 
+/// Window function expressions.
+///
+/// Provides the `Window` builder for rankings, offsets and running aggregates
+/// selected with `QueryBuilder::select_expr()`.
+pub mod window;
+
 /// Schema migration management.
 ///
 /// Contains the `Migrator` struct for registering models and executing
@@ -131,13 +149,16 @@ pub mod pagination;
 ///
 /// This is the main entry point for establishing database connections
 /// and creating query builders or migrators.
-pub use database::{Database, DatabaseBuilder, RawQuery};
+pub use database::{Database, DatabaseBuilder, ModelConnection, RawQuery};
+
+/// Re-export of the `SqlDialect` trait for implementing custom dialects.
+pub use dialect::SqlDialect;
 
 /// Re-export of the `Model` trait and `ColumnInfo` struct.
 ///
 /// The `Model` trait defines the interface for ORM entities, while
 /// `ColumnInfo` contains metadata about individual table columns.
-pub use model::{ColumnInfo, Model};
+pub use model::{ColumnInfo, IndexColumn, IndexInfo, Model};
 
 /// Re-export of `AnyImpl` and `AnyInfo` for dynamic row mapping.
 ///
@@ -145,19 +166,24 @@ pub use model::{ColumnInfo, Model};
 /// providing necessary column metadata via `AnyInfo`.
 pub use any_struct::{AnyImpl, AnyInfo, FromAnyRow};
 
-pub use transaction::Transaction;
+pub use transaction::{IsolationLevel, RetryPolicy, Transaction, TxOptions};
 
 /// Re-export of the `QueryBuilder` for constructing and executing queries.
 ///
 /// `QueryBuilder` provides a fluent interface for building SELECT and INSERT
 /// queries with filtering, ordering, and pagination capabilities.
-pub use query_builder::{Op, QueryBuilder};
+pub use query_builder::{
+    raw, CompiledQuery, Direction, Expression, Fragment, LockMode, LockWait, Nulls, Op, QueryBuilder,
+};
+
+/// Re-export of the window function builder used with `QueryBuilder::select_expr()`.
+pub use window::{FrameBound, Window};
 
 /// Re-export of the `Migrator` for schema migration management.
 ///
 /// `Migrator` handles the registration of models and execution of
 /// migration tasks to create tables and establish relationships.
-pub use migration::Migrator;
+pub use migration::{Migrator, SqlMigration, SqlScript};
 
 /// Re-export of the `Error` type for error handling.
 ///
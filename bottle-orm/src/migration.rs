@@ -14,10 +14,17 @@
 //!
 //! This ensures that foreign keys can reference tables that haven't been created yet.
 //!
+//! Changes that can't be expressed as models (data backfills, triggers, views) can be
+//! embedded from versioned `.sql` files with `embed_migrations!` and registered with
+//! [`Migrator::register_sql`]. They run after the model phases, exactly once per
+//! database, and are tracked in the `_bottle_migrations` table.
+//!
 //! ## Features
 //!
 //! - **Automatic Ordering**: Handles dependencies between tables automatically
 //! - **Idempotent Operations**: Safe to run multiple times (uses IF NOT EXISTS)
+//! - **Cross-Process Locking**: Concurrently starting instances apply migrations one at a time
+//! - **Transactional DDL**: All phases can run in one transaction on engines that roll back schema changes
 //! - **Type Safety**: Leverages Rust's type system for compile-time validation
 //! - **Async Execution**: Non-blocking migration execution
 //!
@@ -62,13 +69,43 @@
 // External Crate Imports
 // ============================================================================
 
+use std::time::{Duration, Instant};
+
 use futures::future::BoxFuture;
+use sqlx::{pool::PoolConnection, Any, AnyConnection, Row};
 
 // ============================================================================
 // Internal Crate Imports
 // ============================================================================
 
-use crate::{database::Database, model::Model};
+use crate::{
+    database::{Database, Drivers},
+    dialect::SqlDialect,
+    model::Model,
+    Error,
+};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Name of the table used to record which SQL migrations have been applied.
+pub const MIGRATIONS_TABLE: &str = "_bottle_migrations";
+
+/// Name of the table holding the SQLite migration lock row.
+pub const MIGRATIONS_LOCK_TABLE: &str = "_bottle_migrations_lock";
+
+/// Key used for `pg_advisory_lock` on PostgreSQL (ASCII "bottle").
+const ADVISORY_LOCK_KEY: i64 = 0x626f_7474_6c65;
+
+/// Lock name used for `GET_LOCK` on MySQL.
+const MYSQL_LOCK_NAME: &str = "bottle_orm_migrations";
+
+/// Default time to wait for the migration lock before giving up.
+pub const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Default age after which a SQLite lock row is considered abandoned.
+pub const DEFAULT_STALE_LOCK_AFTER: Duration = Duration::from_secs(15 * 60);
 
 // ============================================================================
 // Type Aliases
@@ -76,25 +113,27 @@ use crate::{database::Database, model::Model};
 
 /// Type alias for migration tasks (e.g., Create Table, Add Foreign Key).
 ///
-/// Migration tasks are async closures that take a `Database` instance and return
-/// a boxed future that resolves to a Result. This allows for flexible, composable
-/// migration operations.
+/// Migration tasks are async closures that take the `Database` and the connection
+/// the migration runs on, and return a boxed future that resolves to a Result.
+/// This allows for flexible, composable migration operations.
 ///
 /// # Type Definition
 ///
 /// ```rust,ignore
 /// type MigrationTask = Box<
-///     dyn Fn(Database) -> BoxFuture<'static, Result<(), sqlx::Error>> + Send + Sync
+///     dyn for<'c> Fn(&'c Database, &'c mut AnyConnection) -> BoxFuture<'c, Result<(), Error>> + Send + Sync
 /// >;
 /// ```
 ///
 /// # Parameters
 ///
-/// * `Database` - Cloned database instance for the migration operation
+/// * `&Database` - The database being migrated (driver and dialect)
+/// * `&mut AnyConnection` - The connection to execute on; a transaction when the
+///   migration is transactional
 ///
 /// # Returns
 ///
-/// * `BoxFuture<'static, Result<(), sqlx::Error>>` - Async result of the migration
+/// * `BoxFuture<'c, Result<(), Error>>` - Async result of the migration
 ///
 /// # Traits
 ///
@@ -104,14 +143,86 @@ use crate::{database::Database, model::Model};
 /// # Example
 ///
 /// ```rust,ignore
-/// let task: MigrationTask = Box::new(|db: Database| {
+/// let task: MigrationTask = Box::new(|_db, conn| {
 ///     Box::pin(async move {
-///         db.create_table::<User>().await?;
+///         sqlx::query("CREATE VIEW active_users AS SELECT * FROM \"user\"").execute(conn).await?;
 ///         Ok(())
 ///     })
 /// });
 /// ```
-pub type MigrationTask = Box<dyn Fn(Database) -> BoxFuture<'static, Result<(), sqlx::Error>> + Send + Sync>;
+pub type MigrationTask =
+    Box<dyn for<'c> Fn(&'c Database, &'c mut AnyConnection) -> BoxFuture<'c, Result<(), Error>> + Send + Sync>;
+
+// ============================================================================
+// SQL Migrations
+// ============================================================================
+
+/// The SQL script for one direction (up or down) of an embedded migration.
+///
+/// Holds a generic script plus optional driver-specific variants. Driver-specific
+/// variants come from files such as `V001__name.up.postgres.sql` and take
+/// precedence over the generic `V001__name.up.sql`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SqlScript {
+    /// Script used on every driver without a specific variant.
+    pub default: Option<&'static str>,
+
+    /// Script used on PostgreSQL (`.postgres.sql`).
+    pub postgres: Option<&'static str>,
+
+    /// Script used on MySQL (`.mysql.sql`).
+    pub mysql: Option<&'static str>,
+
+    /// Script used on SQLite (`.sqlite.sql`).
+    pub sqlite: Option<&'static str>,
+}
+
+impl SqlScript {
+    /// Returns the script to execute for the given driver, if any.
+    ///
+    /// The driver-specific variant is preferred; otherwise the generic script
+    /// is returned. `None` means the migration has nothing to run on this driver.
+    pub fn for_driver(&self, driver: Drivers) -> Option<&'static str> {
+        let specific = match driver {
+            Drivers::Postgres => self.postgres,
+            Drivers::MySQL => self.mysql,
+            Drivers::SQLite => self.sqlite,
+        };
+
+        specific.or(self.default)
+    }
+}
+
+/// A versioned SQL migration embedded at compile time.
+///
+/// Values of this type are generated by the `embed_migrations!` macro and
+/// registered with [`Migrator::register_sql`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use bottle_orm::embed_migrations;
+///
+/// // migrations/V001__create_stats_view.up.sql
+/// // migrations/V001__create_stats_view.down.sql
+/// let migrations = embed_migrations!("migrations/");
+/// assert_eq!(migrations[0].version, 1);
+/// assert_eq!(migrations[0].name, "create_stats_view");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SqlMigration {
+    /// Version number parsed from the `V<version>__` prefix.
+    pub version: u64,
+
+    /// Descriptive name parsed from the file name.
+    pub name: &'static str,
+
+    /// Script that applies the migration.
+    pub up: SqlScript,
+
+    /// Script that reverts the migration.
+    pub down: SqlScript,
+}
 
 // ============================================================================
 // Migrator Struct
@@ -129,6 +240,28 @@ pub type MigrationTask = Box<dyn Fn(Database) -> BoxFuture<'static, Result<(), s
 /// * `db` - Reference to the database connection
 /// * `tasks` - Queue of table creation tasks
 /// * `fk_task` - Queue of foreign key assignment tasks
+/// * `lock_timeout` - How long to wait for the cross-process migration lock
+/// * `stale_lock_after` - Age after which a SQLite lock row may be taken over
+/// * `conn` - The caller's transaction, for migrators created by `Transaction::migrator()`
+/// * `transactional` - Whether to run all phases in a single transaction
+///
+/// # Concurrency
+///
+/// `run()` and `rollback()` take a cross-process lock before applying any change,
+/// so replicas that boot at the same time migrate one after another:
+///
+/// - **PostgreSQL**: `pg_advisory_lock`, migrating on the connection that holds it
+/// - **MySQL**: `GET_LOCK`, migrating on the connection that holds it
+/// - **SQLite**: a lock row in the `_bottle_migrations_lock` table
+///
+/// A SQLite lock row left behind by a crashed or killed process is taken over
+/// once it is older than `stale_lock_after()` (15 minutes by default).
+///
+/// # Transactions
+///
+/// By default each step commits on its own. `transactional()` runs every phase
+/// in one transaction on engines with transactional DDL (PostgreSQL, SQLite),
+/// and `Transaction::migrator()` runs them inside a transaction you control.
 ///
 /// # Lifecycle
 ///
@@ -168,8 +301,7 @@ pub type MigrationTask = Box<dyn Fn(Database) -> BoxFuture<'static, Result<(), s
 pub struct Migrator<'a> {
     /// Reference to the database connection.
     ///
-    /// This is used to execute migration tasks and is cloned for each task
-    /// to allow async execution without lifetime issues.
+    /// Provides the pool, driver and dialect the migration tasks run with.
     pub(crate) db: &'a Database,
 
     /// Queue of table creation tasks.
@@ -184,6 +316,30 @@ pub struct Migrator<'a> {
     /// These tasks are executed after all table creation tasks complete.
     /// This ensures that referenced tables exist before foreign keys are created.
     pub(crate) fk_task: Vec<MigrationTask>,
+
+    /// Embedded SQL migrations.
+    ///
+    /// These are applied after all model-based tasks, ordered by version.
+    pub(crate) sql_migrations: Vec<SqlMigration>,
+
+    /// Maximum time to wait for the migration lock.
+    ///
+    /// `None` disables locking entirely.
+    pub(crate) lock_timeout: Option<Duration>,
+
+    /// Age after which a SQLite lock row is treated as abandoned.
+    pub(crate) stale_lock_after: Duration,
+
+    /// Connection of the caller's transaction.
+    ///
+    /// Set by `Transaction::migrator()`; all phases then run on it and the
+    /// caller decides whether to commit.
+    pub(crate) conn: Option<&'a mut AnyConnection>,
+
+    /// Whether to run all phases in a single transaction.
+    ///
+    /// Ignored on engines without transactional DDL (MySQL).
+    pub(crate) transactional: bool,
 }
 
 // ============================================================================
@@ -219,7 +375,23 @@ impl<'a> Migrator<'a> {
     /// let migrator = Migrator::new(&db);
     /// ```
     pub fn new(db: &'a Database) -> Self {
-        Self { db, tasks: Vec::new(), fk_task: Vec::new() }
+        Self {
+            db,
+            tasks: Vec::new(),
+            fk_task: Vec::new(),
+            sql_migrations: Vec::new(),
+            lock_timeout: Some(DEFAULT_LOCK_TIMEOUT),
+            stale_lock_after: DEFAULT_STALE_LOCK_AFTER,
+            conn: None,
+            transactional: false,
+        }
+    }
+
+    /// Creates a Migrator that runs on the connection of an open transaction.
+    ///
+    /// Used by `Transaction::migrator()`.
+    pub(crate) fn in_transaction(db: &'a Database, conn: &'a mut AnyConnection) -> Self {
+        Self { conn: Some(conn), ..Self::new(db) }
     }
 
     // ========================================================================
@@ -306,22 +478,20 @@ impl<'a> Migrator<'a> {
         T: Model + 'static + Send + Sync,
     {
         // Create table creation task
-        // This task clones the database and creates the table asynchronously
-        let task = Box::new(|db: Database| -> BoxFuture<'static, Result<(), sqlx::Error>> {
+        // This task creates the table asynchronously on the migration connection
+        let task: MigrationTask = Box::new(|db, conn| {
             Box::pin(async move {
                 // Create table with columns, indexes, and inline constraints
-                db.create_table::<T>().await?;
-                Ok(())
+                db.create_table_on::<T>(conn).await
             })
         });
 
         // Create foreign key assignment task
         // This task runs after all tables are created to ensure references exist
-        let fk_task = Box::new(|db: Database| -> BoxFuture<'static, Result<(), sqlx::Error>> {
+        let fk_task: MigrationTask = Box::new(|db, conn| {
             Box::pin(async move {
                 // Assign foreign key constraints
-                db.assign_foreign_keys::<T>().await?;
-                Ok(())
+                db.assign_foreign_keys_on::<T>(conn).await
             })
         });
 
@@ -333,6 +503,131 @@ impl<'a> Migrator<'a> {
         self
     }
 
+    /// Registers embedded SQL migrations.
+    ///
+    /// The migrations are usually produced by the `embed_migrations!` macro.
+    /// They are applied after the table creation and foreign key phases, in
+    /// ascending version order, and each version is applied only once per
+    /// database (tracked in the `_bottle_migrations` table).
+    ///
+    /// # Arguments
+    ///
+    /// * `migrations` - The embedded migrations to register
+    ///
+    /// # Returns
+    ///
+    /// Returns `self` to enable method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use bottle_orm::embed_migrations;
+    ///
+    /// db.migrator()
+    ///     .register::<User>()
+    ///     .register_sql(embed_migrations!("migrations/"))
+    ///     .run()
+    ///     .await?;
+    /// ```
+    pub fn register_sql(mut self, migrations: &[SqlMigration]) -> Self {
+        self.sql_migrations.extend_from_slice(migrations);
+        self
+    }
+
+    // ========================================================================
+    // Locking Configuration
+    // ========================================================================
+
+    /// Sets how long to wait for the cross-process migration lock.
+    ///
+    /// If another instance holds the lock for longer than `timeout`, `run()`
+    /// and `rollback()` fail with a timeout error instead of waiting forever.
+    /// Defaults to 60 seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Maximum time to wait for the lock
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// db.migrator()
+    ///     .lock_timeout(Duration::from_secs(10))
+    ///     .register::<User>()
+    ///     .run()
+    ///     .await?;
+    /// ```
+    pub fn lock_timeout(mut self, timeout: Duration) -> Self {
+        self.lock_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the age after which a SQLite lock row is considered abandoned.
+    ///
+    /// The SQLite lock is a row that a crashed or killed process cannot delete.
+    /// A waiting migrator takes over a row older than this instead of timing out.
+    /// Set it above the duration of your longest migration. Defaults to 15 minutes.
+    /// Ignored on PostgreSQL and MySQL, whose locks end with the session.
+    ///
+    /// # Arguments
+    ///
+    /// * `age` - Age of the lock row after which it may be taken over
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// db.migrator()
+    ///     .stale_lock_after(Duration::from_secs(60 * 60))
+    ///     .register::<User>()
+    ///     .run()
+    ///     .await?;
+    /// ```
+    pub fn stale_lock_after(mut self, age: Duration) -> Self {
+        self.stale_lock_after = age;
+        self
+    }
+
+    /// Disables the cross-process migration lock.
+    ///
+    /// Only use this when a single process is guaranteed to run migrations,
+    /// or when the database user lacks the privileges to take the lock.
+    pub fn without_lock(mut self) -> Self {
+        self.lock_timeout = None;
+        self
+    }
+
+    // ========================================================================
+    // Transaction Configuration
+    // ========================================================================
+
+    /// Runs all migration phases in a single transaction.
+    ///
+    /// If any step fails, every table, foreign key and SQL migration applied by
+    /// this run is rolled back, leaving the schema untouched. This requires
+    /// transactional DDL (see `SqlDialect::supports_transactional_ddl()`): it
+    /// applies on PostgreSQL and SQLite, while MySQL commits each DDL statement
+    /// implicitly, so there the steps run one by one as usual.
+    ///
+    /// # Returns
+    ///
+    /// Returns `self` to enable method chaining
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// db.migrator()
+    ///     .transactional()
+    ///     .register::<User>()
+    ///     .register::<Post>()
+    ///     .register_sql(embed_migrations!("migrations/"))
+    ///     .run()
+    ///     .await?;
+    /// ```
+    pub fn transactional(mut self) -> Self {
+        self.transactional = true;
+        self
+    }
+
     // ========================================================================
     // Migration Execution
     // ========================================================================
@@ -351,12 +646,23 @@ impl<'a> Migrator<'a> {
     /// - Creates foreign key constraints between tables
     /// - Checks for existing constraints to avoid duplicates
     ///
+    /// **Phase 3: SQL Migrations**
+    /// - Applies pending embedded SQL migrations in version order
+    /// - Each migration and its history record run in a single transaction
+    ///   (a savepoint when the whole run is transactional)
+    ///
+    /// All phases run while holding the cross-process migration lock (see
+    /// [`lock_timeout()`](#method.lock_timeout)). The lock is released even if
+    /// a phase fails. With [`transactional()`](#method.transactional) all phases
+    /// are committed together; a migrator from `Transaction::migrator()` leaves
+    /// the commit to the caller.
+    ///
     /// If any task fails, the entire migration is aborted and an error is returned.
     ///
     /// # Returns
     ///
     /// * `Ok(Database)` - Cloned database instance on success
-    /// * `Err(sqlx::Error)` - Database error during migration
+    /// * `Err(Error)` - Database error during migration
     ///
     /// # Error Handling
     ///
@@ -412,16 +718,114 @@ impl<'a> Migrator<'a> {
     /// * [`register()`](#method.register) - For registering models
     /// * [`Database::create_table()`] - For manual table creation
     /// * [`Database::assign_foreign_keys()`] - For manual FK assignment
-    pub async fn run(self) -> Result<Database, sqlx::Error> {
+    pub async fn run(self) -> Result<Database, Error> {
+        self.execute(None).await
+    }
+
+    /// Reverts the most recently applied SQL migrations.
+    ///
+    /// Executes the `down` script of the last `steps` applied migrations, newest
+    /// first, and removes them from the `_bottle_migrations` table. Model-based
+    /// tables are never dropped. Runs while holding the migration lock.
+    ///
+    /// # Arguments
+    ///
+    /// * `steps` - Number of migrations to revert
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Database)` - Cloned database instance on success
+    /// * `Err(Error)` - A migration has no `down` script, is not registered, or the database failed
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Revert the last embedded migration
+    /// db.migrator()
+    ///     .register_sql(embed_migrations!("migrations/"))
+    ///     .rollback(1)
+    ///     .await?;
+    /// ```
+    pub async fn rollback(self, steps: usize) -> Result<Database, Error> {
+        self.execute(Some(steps)).await
+    }
+
+    /// Takes the migration lock, picks the connection and runs `apply()`, or
+    /// `revert()` when `revert_steps` is given.
+    async fn execute(mut self, revert_steps: Option<usize>) -> Result<Database, Error> {
+        let db = self.db;
+
+        // Inside the caller's transaction, which commits or rolls back everything
+        if let Some(conn) = self.conn.take() {
+            if let Some(timeout) = self.lock_timeout {
+                lock_in_transaction(conn, db.driver, timeout).await?;
+            }
+            self.migrate(conn, revert_steps).await?;
+            return Ok(db.clone());
+        }
+
+        let mut lock = match self.lock_timeout {
+            Some(timeout) => Some(MigrationLock::acquire(db, timeout, self.stale_lock_after).await?),
+            None => None,
+        };
+
+        // Session locks belong to their connection, so the migration runs on it
+        let result = match &mut lock {
+            Some(MigrationLock::Session(session)) => self.migrate_on(&mut session.conn, revert_steps).await,
+            _ => match db.pool.acquire().await {
+                Ok(mut conn) => self.migrate_on(&mut conn, revert_steps).await,
+                Err(e) => Err(e.into()),
+            },
+        };
+
+        if let Some(lock) = lock {
+            let released = lock.release(db).await;
+
+            // A failed migration is the error worth reporting
+            match (&result, released) {
+                (Err(_), Err(e)) => log::error!("Failed to release the migration lock: {}", e),
+                (Ok(()), Err(e)) => return Err(e),
+                (_, Ok(())) => {}
+            }
+        }
+
+        result.map(|()| db.clone())
+    }
+
+    /// Runs the migration on `conn`, in one transaction if requested and supported.
+    async fn migrate_on(self, conn: &mut AnyConnection, revert_steps: Option<usize>) -> Result<(), Error> {
+        let db = self.db;
+
+        if self.transactional && db.dialect.supports_transactional_ddl() {
+            let mut tx = sqlx::Connection::begin(conn).await?;
+            self.migrate(&mut tx, revert_steps).await?;
+            return Ok(tx.commit().await?);
+        }
+
+        if self.transactional {
+            log::warn!("{:?} does not support transactional DDL; migrating step by step", db.driver);
+        }
+
+        self.migrate(conn, revert_steps).await
+    }
+
+    /// Dispatches to `apply()` or `revert()`.
+    async fn migrate(self, conn: &mut AnyConnection, revert_steps: Option<usize>) -> Result<(), Error> {
+        match revert_steps {
+            Some(steps) => self.revert(conn, steps).await,
+            None => self.apply(conn).await,
+        }
+    }
+
+    /// Executes all migration phases on `conn`. Called by `run()` once the lock is held.
+    async fn apply(self, conn: &mut AnyConnection) -> Result<(), Error> {
         // ====================================================================
         // Phase 1: Execute Table Creation Tasks
         // ====================================================================
         // Create all tables in the order they were registered.
         // This ensures that models are created before their dependents.
         for task in self.tasks {
-            // Clone the database for the async task
-            // This is safe because Database contains a connection pool
-            (task)(self.db.clone()).await?;
+            (task)(self.db, &mut *conn).await?;
         }
 
         // ====================================================================
@@ -431,11 +835,316 @@ impl<'a> Migrator<'a> {
         // This prevents errors where a foreign key references a table
         // that hasn't been created yet.
         for task in self.fk_task {
-            // Clone the database for the async task
-            (task)(self.db.clone()).await?;
+            (task)(self.db, &mut *conn).await?;
+        }
+
+        // ====================================================================
+        // Phase 3: Apply Embedded SQL Migrations
+        // ====================================================================
+        // SQL migrations run last so they can reference model tables.
+        if !self.sql_migrations.is_empty() {
+            let migrations = sorted_migrations(self.sql_migrations)?;
+            ensure_migrations_table(conn, self.db.dialect.as_ref()).await?;
+            let applied = applied_versions(conn, self.db.dialect.as_ref()).await?;
+
+            for migration in migrations.iter().filter(|m| !applied.contains(&(m.version as i64))) {
+                log::info!("Applying migration V{:03}__{}", migration.version, migration.name);
+
+                // Nested in a transactional run, this becomes a savepoint
+                let mut tx = sqlx::Connection::begin(&mut *conn).await?;
+
+                if let Some(sql) = migration.up.for_driver(self.db.driver) {
+                    sqlx::raw_sql(sql).execute(&mut *tx).await?;
+                }
+
+                let dialect = self.db.dialect.as_ref();
+                let insert_query = format!(
+                    "INSERT INTO {} ({}, {}, {}) VALUES ({})",
+                    dialect.quote_ident(MIGRATIONS_TABLE),
+                    dialect.quote_ident("version"),
+                    dialect.quote_ident("name"),
+                    dialect.quote_ident("applied_at"),
+                    placeholders(dialect, 3)
+                );
+                sqlx::query(&insert_query)
+                    .bind(migration.version as i64)
+                    .bind(migration.name)
+                    .bind(chrono::Utc::now().to_rfc3339())
+                    .execute(&mut *tx)
+                    .await?;
+
+                tx.commit().await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reverts SQL migrations on `conn`. Called by `rollback()` once the lock is held.
+    async fn revert(self, conn: &mut AnyConnection, steps: usize) -> Result<(), Error> {
+        let migrations = sorted_migrations(self.sql_migrations)?;
+        ensure_migrations_table(conn, self.db.dialect.as_ref()).await?;
+
+        let mut applied = applied_versions(conn, self.db.dialect.as_ref()).await?;
+        applied.sort_unstable_by(|a, b| b.cmp(a));
+
+        for version in applied.into_iter().take(steps) {
+            let migration = migrations.iter().find(|m| m.version as i64 == version).ok_or_else(|| {
+                Error::InvalidArgument(format!("applied migration version {} is not registered", version))
+            })?;
+
+            let down = migration.down.for_driver(self.db.driver);
+            if down.is_none() && migration.up.for_driver(self.db.driver).is_some() {
+                return Err(Error::InvalidArgument(format!(
+                    "migration V{:03}__{} has no down script",
+                    migration.version, migration.name
+                )));
+            }
+
+            log::info!("Reverting migration V{:03}__{}", migration.version, migration.name);
+
+            let mut tx = sqlx::Connection::begin(&mut *conn).await?;
+
+            if let Some(sql) = down {
+                sqlx::raw_sql(sql).execute(&mut *tx).await?;
+            }
+
+            let dialect = self.db.dialect.as_ref();
+            let delete_query = format!(
+                "DELETE FROM {} WHERE {} = {}",
+                dialect.quote_ident(MIGRATIONS_TABLE),
+                dialect.quote_ident("version"),
+                placeholders(dialect, 1)
+            );
+            sqlx::query(&delete_query).bind(version).execute(&mut *tx).await?;
+
+            tx.commit().await?;
         }
 
-        // Return cloned database instance for continued use
-        Ok(self.db.clone())
+        Ok(())
     }
 }
+
+// ============================================================================
+// Migration Lock
+// ============================================================================
+
+/// A held cross-process migration lock.
+///
+/// PostgreSQL and MySQL locks are session-scoped, so the connection that took
+/// the lock is kept, and migrated on, until release. SQLite uses a lock row instead.
+enum MigrationLock {
+    /// Advisory lock held by a pool connection (PostgreSQL, MySQL).
+    Session(SessionLock),
+
+    /// Lock row inserted into `_bottle_migrations_lock` (SQLite).
+    Row,
+}
+
+/// A pool connection holding a session-scoped migration lock.
+///
+/// If it is dropped without a successful `release()` (the migration future was
+/// cancelled, panicked or failed to unlock), the connection is closed instead of
+/// returned to the pool, which ends the session and frees the lock.
+struct SessionLock {
+    conn: PoolConnection<Any>,
+    released: bool,
+}
+
+impl SessionLock {
+    /// Wraps `conn` before the lock is requested, so no path returns it to the pool locked.
+    fn new(conn: PoolConnection<Any>) -> Self {
+        Self { conn, released: false }
+    }
+}
+
+impl Drop for SessionLock {
+    fn drop(&mut self) {
+        if !self.released {
+            self.conn.close_on_drop();
+        }
+    }
+}
+
+impl MigrationLock {
+    /// Acquires the migration lock, waiting at most `timeout`.
+    ///
+    /// On SQLite a lock row older than `stale_after` is deleted and taken over.
+    async fn acquire(db: &Database, timeout: Duration, stale_after: Duration) -> Result<Self, Error> {
+        match db.driver {
+            Drivers::Postgres => {
+                let mut session = SessionLock::new(db.pool.acquire().await?);
+                let conn = &mut *session.conn;
+
+                // pg_advisory_lock honours lock_timeout; a value of 0 would mean "wait forever"
+                let millis = timeout.as_millis().max(1);
+                sqlx::query(&format!("SET lock_timeout = {}", millis)).execute(&mut *conn).await?;
+
+                let locked = sqlx::query("SELECT pg_advisory_lock($1)").bind(ADVISORY_LOCK_KEY).execute(&mut *conn).await;
+                sqlx::query("RESET lock_timeout").execute(&mut *conn).await?;
+
+                match locked {
+                    Ok(_) => Ok(MigrationLock::Session(session)),
+                    Err(sqlx::Error::Database(e)) if e.code().as_deref() == Some("55P03") => Err(lock_timeout_error()),
+                    Err(e) => Err(e.into()),
+                }
+            }
+            Drivers::MySQL => {
+                let mut session = SessionLock::new(db.pool.acquire().await?);
+                let conn = &mut *session.conn;
+
+                // GET_LOCK takes whole seconds; round up so short timeouts still wait
+                let seconds = timeout.as_secs() + u64::from(timeout.subsec_nanos() > 0);
+                let row = sqlx::query("SELECT GET_LOCK(?, ?)")
+                    .bind(MYSQL_LOCK_NAME)
+                    .bind(seconds as i64)
+                    .fetch_one(&mut *conn)
+                    .await?;
+
+                match row.try_get::<Option<i64>, _>(0)? {
+                    Some(1) => Ok(MigrationLock::Session(session)),
+                    _ => Err(lock_timeout_error()),
+                }
+            }
+            Drivers::SQLite => {
+                let create_query = format!(
+                    "CREATE TABLE IF NOT EXISTS \"{}\" (\"id\" INTEGER PRIMARY KEY, \"locked_at\" TEXT NOT NULL)",
+                    MIGRATIONS_LOCK_TABLE
+                );
+                sqlx::query(&create_query).execute(&db.pool).await?;
+
+                let insert_query = format!("INSERT INTO \"{}\" (\"id\", \"locked_at\") VALUES (1, ?)", MIGRATIONS_LOCK_TABLE);
+                let stale_query =
+                    format!("DELETE FROM \"{}\" WHERE \"id\" = 1 AND \"locked_at\" < ?", MIGRATIONS_LOCK_TABLE);
+                let stale_after = chrono::Duration::from_std(stale_after).unwrap_or(chrono::Duration::MAX);
+                let started = Instant::now();
+
+                loop {
+                    let now = chrono::Utc::now();
+                    let cutoff = now.checked_sub_signed(stale_after).unwrap_or(chrono::DateTime::UNIX_EPOCH);
+
+                    // A row this old was left behind by a process that died while migrating
+                    let taken_over =
+                        sqlx::query(&stale_query).bind(lock_timestamp(cutoff)).execute(&db.pool).await?.rows_affected();
+                    if taken_over > 0 {
+                        log::warn!("Took over a stale migration lock older than {:?}", stale_after);
+                    }
+
+                    let inserted = sqlx::query(&insert_query).bind(lock_timestamp(now)).execute(&db.pool).await;
+
+                    match inserted.map_err(Error::from) {
+                        Ok(_) => return Ok(MigrationLock::Row),
+                        // The lock row already exists: another instance is migrating
+                        Err(Error::UniqueViolation { .. }) if started.elapsed() < timeout => {
+                            tokio::time::sleep(Duration::from_millis(50)).await;
+                        }
+                        Err(Error::UniqueViolation { .. }) => return Err(lock_timeout_error()),
+                        Err(e) => return Err(e),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Releases the migration lock.
+    async fn release(self, db: &Database) -> Result<(), Error> {
+        match self {
+            MigrationLock::Session(mut session) => {
+                let query = match db.driver {
+                    Drivers::Postgres => sqlx::query("SELECT pg_advisory_unlock($1)").bind(ADVISORY_LOCK_KEY),
+                    _ => sqlx::query("SELECT RELEASE_LOCK(?)").bind(MYSQL_LOCK_NAME),
+                };
+                query.execute(&mut *session.conn).await?;
+                session.released = true;
+            }
+            MigrationLock::Row => {
+                let query = format!("DELETE FROM \"{}\" WHERE \"id\" = 1", MIGRATIONS_LOCK_TABLE);
+                sqlx::query(&query).execute(&db.pool).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Takes the migration lock inside the caller's transaction.
+///
+/// PostgreSQL takes a transaction-scoped advisory lock, released on commit or
+/// rollback. MySQL and SQLite take no lock in this mode; use `Database::migrator()`
+/// when several instances may migrate at the same time.
+async fn lock_in_transaction(conn: &mut AnyConnection, driver: Drivers, timeout: Duration) -> Result<(), Error> {
+    if let Drivers::Postgres = driver {
+        let millis = timeout.as_millis().max(1);
+        sqlx::query(&format!("SET LOCAL lock_timeout = {}", millis)).execute(&mut *conn).await?;
+
+        let locked = sqlx::query("SELECT pg_advisory_xact_lock($1)").bind(ADVISORY_LOCK_KEY).execute(&mut *conn).await;
+        match locked {
+            Ok(_) => {}
+            Err(sqlx::Error::Database(e)) if e.code().as_deref() == Some("55P03") => return Err(lock_timeout_error()),
+            Err(e) => return Err(e.into()),
+        }
+
+        sqlx::query("SET LOCAL lock_timeout TO DEFAULT").execute(&mut *conn).await?;
+    }
+
+    Ok(())
+}
+
+/// Formats a SQLite lock row timestamp.
+///
+/// The format is fixed-width UTC, so comparing timestamps as text orders them in time.
+fn lock_timestamp(at: chrono::DateTime<chrono::Utc>) -> String {
+    at.to_rfc3339_opts(chrono::SecondsFormat::Micros, true)
+}
+
+/// Error returned when the migration lock could not be acquired in time.
+fn lock_timeout_error() -> Error {
+    Error::Timeout(sqlx::Error::Io(std::io::Error::new(
+        std::io::ErrorKind::TimedOut,
+        "timed out waiting for the migration lock held by another instance",
+    )))
+}
+
+// ============================================================================
+// SQL Migration Helpers
+// ============================================================================
+
+/// Sorts migrations by version and rejects duplicate versions.
+fn sorted_migrations(mut migrations: Vec<SqlMigration>) -> Result<Vec<SqlMigration>, Error> {
+    migrations.sort_by_key(|m| m.version);
+
+    if let Some(pair) = migrations.windows(2).find(|w| w[0].version == w[1].version) {
+        return Err(Error::InvalidArgument(format!(
+            "duplicate migration version {} ({} and {})",
+            pair[0].version, pair[0].name, pair[1].name
+        )));
+    }
+
+    Ok(migrations)
+}
+
+/// Creates the migration history table if it does not exist.
+async fn ensure_migrations_table(conn: &mut AnyConnection, dialect: &dyn SqlDialect) -> Result<(), Error> {
+    let q = |ident: &str| dialect.quote_ident(ident);
+    let query = format!(
+        "CREATE TABLE IF NOT EXISTS {} ({} BIGINT PRIMARY KEY, {} TEXT NOT NULL, {} TEXT NOT NULL)",
+        q(MIGRATIONS_TABLE),
+        q("version"),
+        q("name"),
+        q("applied_at")
+    );
+    sqlx::query(&query).execute(conn).await?;
+    Ok(())
+}
+
+/// Returns the versions already recorded in the migration history table.
+async fn applied_versions(conn: &mut AnyConnection, dialect: &dyn SqlDialect) -> Result<Vec<i64>, Error> {
+    let query = format!("SELECT {} FROM {}", dialect.quote_ident("version"), dialect.quote_ident(MIGRATIONS_TABLE));
+    let rows = sqlx::query(&query).fetch_all(conn).await?;
+    Ok(rows.iter().map(|row| row.try_get::<i64, _>(0)).collect::<Result<_, _>>()?)
+}
+
+/// Builds a comma-separated placeholder list for the given dialect.
+fn placeholders(dialect: &dyn SqlDialect, count: usize) -> String {
+    (1..=count).map(|i| dialect.placeholder(i)).collect::<Vec<_>>().join(", ")
+}
@@ -66,12 +66,19 @@
 //! ## Supported ORM Attributes
 //!
 //! - `#[orm(primary_key)]` - Marks field as primary key
+//! - `#[orm(primary_key(a, b))]` - Struct-level composite primary key
+//! - `#[orm(index(columns(a, b desc), unique, where = "...", using = "gin"))]` - Struct-level index
 //! - `#[orm(unique)]` - Adds UNIQUE constraint
 //! - `#[orm(index)]` - Creates database index
 //! - `#[orm(size = N)]` - Sets VARCHAR size (for String fields)
 //! - `#[orm(create_time)]` - Auto-populate with current timestamp on creation
 //! - `#[orm(update_time)]` - Auto-update timestamp on modification (future feature)
 //! - `#[orm(foreign_key = "Table::Column")]` - Defines foreign key relationship
+//! - `#[orm(default = "...")]` - Column DEFAULT (literal or SQL expression)
+//! - `#[orm(check = "...")]` - Column CHECK constraint
+//! - `#[orm(generated = "...", stored)]` - Generated column, never written by the ORM
+//! - `#[orm(auto_increment)]` - Database-assigned integer key, skipped on insert
+//! - `#[orm(sql_type(postgres = "...", mysql = "...", sqlite = "..."))]` - Per-driver DDL type override
 
 // ============================================================================
 // External Crate Imports
@@ -79,6 +86,20 @@
 
 use std::collections::HashMap;
 
+use futures::future::BoxFuture;
+use sqlx::{Any, Encode, Type};
+
+// ============================================================================
+// Internal Crate Imports
+// ============================================================================
+
+use crate::{
+    any_struct::FromAnyRow,
+    database::{Drivers, ModelConnection},
+    dialect::SqlDialect,
+    AnyImpl, Error,
+};
+
 // ============================================================================
 // Column Metadata Structure
 // ============================================================================
@@ -102,6 +123,13 @@ use std::collections::HashMap;
 /// * `index` - Whether to create an index on this column
 /// * `foreign_table` - Name of referenced table (for foreign keys)
 /// * `foreign_key` - Name of referenced column (for foreign keys)
+/// * `default` - SQL DEFAULT expression
+/// * `check` - SQL CHECK constraint expression
+/// * `generated` - Expression of a generated column
+/// * `stored` - Whether the generated column is STORED rather than VIRTUAL
+/// * `auto_increment` - Whether the database assigns the value on insert
+/// * `postgres_type` / `mysql_type` / `sqlite_type` - Per-driver DDL type overrides
+/// * `sensitive` - Whether bound values are redacted in debug output
 ///
 /// # Example
 ///
@@ -127,7 +155,9 @@ use std::collections::HashMap;
 ///
 /// # SQL Type Mapping
 ///
-/// The `sql_type` field contains the SQL type based on the Rust type:
+/// The `sql_type` field contains the logical SQL type based on the Rust type.
+/// It drives value binding at runtime and is translated to a driver-specific
+/// type when tables are created (see [`ColumnInfo::ddl_type`]):
 ///
 /// - `i32` → `"INTEGER"`
 /// - `i64` → `"BIGINT"`
@@ -343,6 +373,216 @@ pub struct ColumnInfo {
     /// // Records with deleted_at set will be excluded from queries
     /// ```
     pub soft_delete: bool,
+
+    /// SQL expression used as the column's `DEFAULT`.
+    ///
+    /// Set via `#[orm(default = ...)]`. String literals are emitted verbatim, so
+    /// they may hold any SQL expression; text values must carry their own quotes.
+    /// Numeric and boolean literals are converted to SQL.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// #[orm(default = "'active'")]
+    /// status: String,
+    /// // default: Some("'active'")
+    /// // SQL: "status" TEXT NOT NULL DEFAULT 'active'
+    /// ```
+    pub default: Option<&'static str>,
+
+    /// SQL expression of a column-level `CHECK` constraint.
+    ///
+    /// Set via `#[orm(check = "...")]` attribute.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// #[orm(check = "age >= 0")]
+    /// age: i32,
+    /// // check: Some("age >= 0")
+    /// // SQL: "age" INTEGER NOT NULL CHECK (age >= 0)
+    /// ```
+    pub check: Option<&'static str>,
+
+    /// Expression of a generated (computed) column.
+    ///
+    /// Set via `#[orm(generated = "...")]` attribute. Generated columns are
+    /// computed by the database, so `insert()` and `updates()` never write them.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// #[orm(generated = "price * quantity", stored)]
+    /// total: f64,
+    /// // generated: Some("price * quantity"), stored: true
+    /// // SQL: "total" DOUBLE PRECISION GENERATED ALWAYS AS (price * quantity) STORED
+    /// ```
+    pub generated: Option<&'static str>,
+
+    /// Whether a generated column is `STORED` rather than `VIRTUAL`.
+    ///
+    /// Set via the `stored` flag next to `generated`. PostgreSQL before version 18
+    /// only supports stored generated columns, so they are always created as
+    /// `STORED` there.
+    pub stored: bool,
+
+    /// Whether the database generates the value of this integer column.
+    ///
+    /// Set via `#[orm(auto_increment)]` attribute. Rendered as
+    /// `GENERATED BY DEFAULT AS IDENTITY` on PostgreSQL, `AUTO_INCREMENT` on MySQL
+    /// and `INTEGER PRIMARY KEY AUTOINCREMENT` on SQLite, where it must be the
+    /// model's only primary key. `insert()` never sends the column, so the
+    /// database always assigns it.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// #[orm(primary_key, auto_increment)]
+    /// id: i64,
+    /// // auto_increment: true
+    /// ```
+    pub auto_increment: bool,
+
+    /// DDL type used instead of the mapped type on PostgreSQL.
+    ///
+    /// Set via `#[orm(sql_type(postgres = "..."))]` attribute.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// #[orm(sql_type(postgres = "CITEXT", mysql = "VARCHAR(320)"))]
+    /// email: String,
+    /// // postgres_type: Some("CITEXT"), mysql_type: Some("VARCHAR(320)")
+    /// ```
+    pub postgres_type: Option<&'static str>,
+
+    /// DDL type used instead of the mapped type on MySQL.
+    ///
+    /// Set via `#[orm(sql_type(mysql = "..."))]` attribute.
+    pub mysql_type: Option<&'static str>,
+
+    /// DDL type used instead of the mapped type on SQLite.
+    ///
+    /// Set via `#[orm(sql_type(sqlite = "..."))]` attribute.
+    pub sqlite_type: Option<&'static str>,
+
+    /// Whether values bound for this column are redacted in debug output.
+    ///
+    /// Set via `#[orm(sensitive)]` attribute. Affects `to_sql_with_params()`,
+    /// `interpolated_sql()` and the SQL logged by `debug()`; the real value is
+    /// still sent to the database.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// #[orm(sensitive)]
+    /// password_hash: String,
+    /// // sensitive: true
+    /// ```
+    pub sensitive: bool,
+}
+
+impl ColumnInfo {
+    /// Resolves the column type used in `CREATE TABLE` for the given dialect.
+    ///
+    /// A per-driver override from `#[orm(sql_type(...))]` always wins. Otherwise
+    /// the logical `sql_type` is translated by [`SqlDialect::type_name`]; the
+    /// built-in dialects map it as follows:
+    ///
+    /// | Logical type       | PostgreSQL | MySQL                                  | SQLite |
+    /// |--------------------|------------|----------------------------------------|--------|
+    /// | `UUID`             | `UUID`     | `CHAR(36)`                             | `TEXT` |
+    /// | `JSON` / `JSONB`   | as-is      | `JSON`                                 | `TEXT` |
+    /// | `TIMESTAMPTZ`      | as-is      | `DATETIME(6)`                          | `TEXT` |
+    /// | `TIMESTAMP`        | as-is      | `DATETIME(6)`                          | `TEXT` |
+    /// | `DATE` / `TIME`    | as-is      | as-is                                  | `TEXT` |
+    /// | `DOUBLE PRECISION` | as-is      | `DOUBLE`                               | `REAL` |
+    /// | `TEXT`             | `TEXT`     | `VARCHAR(255)` when keyed, else `TEXT` | `TEXT` |
+    ///
+    /// # Arguments
+    ///
+    /// * `dialect` - The dialect the DDL is generated for
+    /// * `keyed` - Whether the column is part of a key or index (MySQL cannot index unsized `TEXT`)
+    pub(crate) fn ddl_type(&self, dialect: &dyn SqlDialect, keyed: bool) -> String {
+        let override_type = match dialect.driver() {
+            Drivers::Postgres => self.postgres_type,
+            Drivers::MySQL => self.mysql_type,
+            Drivers::SQLite => self.sqlite_type,
+        };
+
+        match override_type {
+            Some(sql_type) => sql_type.to_string(),
+            None => dialect.type_name(self.sql_type, keyed),
+        }
+    }
+}
+
+// ============================================================================
+// Index Metadata Structures
+// ============================================================================
+
+/// A single column of a struct-level index, with its sort direction.
+///
+/// # Fields
+///
+/// * `name` - Column name (field name from struct)
+/// * `descending` - Whether the column is indexed in descending order
+#[derive(Debug, Clone)]
+pub struct IndexColumn {
+    /// The column name as declared on the struct.
+    pub name: &'static str,
+
+    /// Whether the column is indexed in `DESC` order.
+    ///
+    /// Set by writing `desc` after the column name, e.g. `columns(created_at desc)`.
+    pub descending: bool,
+}
+
+/// Metadata for an index declared with the struct-level `#[orm(index(...))]` attribute.
+///
+/// Unlike the field-level `#[orm(index)]` flag, these indexes may span several
+/// columns, be partial (`WHERE ...`) and select an index method (`USING ...`).
+///
+/// # Fields
+///
+/// * `name` - Index name (defaults to `idx_{table}_{col1}_{col2}`)
+/// * `columns` - Indexed columns, in order
+/// * `unique` - Whether a `UNIQUE INDEX` is created
+/// * `predicate` - Optional `WHERE` clause for partial indexes
+/// * `method` - Optional index method (e.g. `"gin"`, `"btree"`, `"hash"`)
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[derive(Model)]
+/// #[orm(index(name = "idx_active_posts", columns(author_id, created_at desc), where = "deleted_at IS NULL"))]
+/// struct Post {
+///     #[orm(primary_key)]
+///     id: i32,
+///     author_id: i32,
+///     created_at: DateTime<Utc>,
+///     deleted_at: Option<DateTime<Utc>>,
+/// }
+///
+/// // SQL: CREATE INDEX IF NOT EXISTS "idx_active_posts" ON "post" ("author_id", "created_at" DESC)
+/// //      WHERE deleted_at IS NULL
+/// ```
+#[derive(Debug, Clone)]
+pub struct IndexInfo {
+    /// The index name.
+    pub name: &'static str,
+
+    /// The indexed columns, in index order.
+    pub columns: Vec<IndexColumn>,
+
+    /// Whether the index enforces uniqueness.
+    pub unique: bool,
+
+    /// Raw SQL predicate for partial indexes.
+    ///
+    /// Supported on PostgreSQL and SQLite. MySQL has no partial indexes, so
+    /// `create_table` returns an error for such declarations.
+    pub predicate: Option<&'static str>,
+
+    /// Index method passed to `USING`.
+    ///
+    /// Emitted before the column list on PostgreSQL and after it on MySQL.
+    /// Ignored on SQLite, which only has B-tree indexes.
+    pub method: Option<&'static str>,
 }
 
 // ============================================================================
@@ -367,6 +607,13 @@ pub struct ColumnInfo {
 /// * `active_columns()` - Returns column names
 /// * `to_map()` - Serializes instance to a HashMap
 ///
+/// # Provided Methods
+///
+/// * `primary_keys()` - Returns the primary key column names
+/// * `indexes()` - Returns struct-level index declarations
+/// * `save()`, `delete()`, `reload()` - Persist this instance by primary key
+/// * `find()` - Loads an instance by primary key
+///
 /// # Example with Derive
 ///
 /// ```rust,ignore
@@ -565,6 +812,186 @@ pub trait Model {
     /// assert_eq!(map.get("age"), Some(&"25".to_string()));
     /// ```
     fn to_map(&self) -> HashMap<String, String>;
+
+    /// Returns the names of the primary key columns, in key order.
+    ///
+    /// Single-column keys return one element. Composite keys declared with the
+    /// struct-level `#[orm(primary_key(a, b))]` attribute (or by marking several
+    /// fields with `#[orm(primary_key)]`) return every key column.
+    ///
+    /// The default implementation collects the columns flagged `is_primary_key`
+    /// in declaration order; the derive macro overrides it to preserve the order
+    /// given in the struct-level attribute.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[derive(Model)]
+    /// #[orm(primary_key(order_id, product_id))]
+    /// struct OrderItem {
+    ///     order_id: i32,
+    ///     product_id: i32,
+    ///     quantity: i32,
+    /// }
+    ///
+    /// assert_eq!(OrderItem::primary_keys(), vec!["order_id", "product_id"]);
+    /// ```
+    fn primary_keys() -> Vec<&'static str> {
+        Self::columns().into_iter().filter(|c| c.is_primary_key).map(|c| c.name).collect()
+    }
+
+    /// Returns the indexes declared with the struct-level `#[orm(index(...))]` attribute.
+    ///
+    /// Field-level `#[orm(index)]` flags are reported through `columns()` instead.
+    /// The default implementation returns no indexes.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[derive(Model)]
+    /// #[orm(index(columns(tenant_id, email), unique))]
+    /// struct Account {
+    ///     #[orm(primary_key)]
+    ///     id: i32,
+    ///     tenant_id: i32,
+    ///     email: String,
+    /// }
+    ///
+    /// assert_eq!(Account::indexes()[0].name, "idx_account_tenant_id_email");
+    /// ```
+    fn indexes() -> Vec<IndexInfo> {
+        Vec::new()
+    }
+
+    /// Inserts this model, or updates the row with the same primary key if there is one.
+    ///
+    /// Runs a single upsert on the primary key (`SqlDialect::upsert`), so
+    /// concurrent saves never insert the row twice and soft-deleted rows are
+    /// updated in place. As with `QueryBuilder::updates()`, fields that are
+    /// `None` are left unchanged.
+    ///
+    /// A model whose `#[orm(auto_increment)]` primary key is `0` has not been
+    /// inserted yet: it is inserted, and `self` is reloaded with the key the
+    /// database assigned, so saving it again updates that row.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - `&Database` or `&mut Transaction`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The row was inserted or updated
+    /// * `Err(Error::InvalidArgument)` - The model has no primary key
+    /// * `Err(Error)` - Database error
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut user = User { id: 0, name: "Ada".to_string() };
+    /// user.save(&db).await?; // INSERT, user.id is now set
+    ///
+    /// user.name = "Ada Lovelace".to_string();
+    /// let mut tx = db.begin().await?;
+    /// user.save(&mut tx).await?; // UPDATE of the same row
+    /// tx.commit().await?;
+    /// ```
+    fn save<'s, C>(&'s mut self, mut conn: C) -> BoxFuture<'s, Result<(), Error>>
+    where
+        Self: Sized + FromAnyRow + AnyImpl + Send + Sync + Unpin,
+        C: ModelConnection + 's,
+    {
+        Box::pin(async move {
+            // A zero auto-increment key marks a model that was never inserted
+            let values = Model::to_map(&*self);
+            let new_key = <Self as Model>::columns()
+                .into_iter()
+                .find(|c| c.is_primary_key && c.auto_increment && values.get(c.name).is_some_and(|v| v == "0"));
+
+            match new_key {
+                Some(key) => {
+                    let id = conn.model::<Self>().insert_returning_key(self, key.name).await?;
+                    *self = conn.model::<Self>().with_deleted().where_primary_key("save", vec![id]).first().await?;
+                }
+                None => conn.model::<Self>().upsert_by_key(self).await?,
+            }
+            Ok(())
+        })
+    }
+
+    /// Deletes the row with this model's primary key.
+    ///
+    /// Models with a `#[orm(soft_delete)]` column are soft-deleted, like
+    /// `QueryBuilder::delete()`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The number of rows deleted (0 if the row was already gone)
+    /// * `Err(Error)` - Database error or missing primary key
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// user.delete(&db).await?;
+    /// ```
+    fn delete<'s, C>(&'s self, mut conn: C) -> BoxFuture<'s, Result<u64, Error>>
+    where
+        Self: Sized + Send + Sync + Unpin,
+        C: ModelConnection + 's,
+    {
+        Box::pin(async move { conn.model::<Self>().where_key_of("delete", self).delete().await })
+    }
+
+    /// Replaces this model with the current row from the database.
+    ///
+    /// The row is looked up by primary key, soft-deleted rows included.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - `self` now holds the stored values
+    /// * `Err(Error::NotFound)` - The row no longer exists
+    /// * `Err(Error)` - Database error or missing primary key
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// db.model::<User>().filter("id", Op::Eq, user.id).update("name", "Grace").await?;
+    /// user.reload(&db).await?;
+    /// ```
+    fn reload<'s, C>(&'s mut self, mut conn: C) -> BoxFuture<'s, Result<(), Error>>
+    where
+        Self: Sized + FromAnyRow + AnyImpl + Send + Sync + Unpin,
+        C: ModelConnection + 's,
+    {
+        Box::pin(async move {
+            *self = conn.model::<Self>().with_deleted().where_key_of("reload", self).first().await?;
+            Ok(())
+        })
+    }
+
+    /// Finds the model with the given primary key.
+    ///
+    /// Shorthand for `conn.model::<Self>().find(id)`: models with a composite
+    /// primary key are rejected, and soft-deleted rows are not returned.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Self))` - The model
+    /// * `Ok(None)` - No row has this primary key
+    /// * `Err(Error)` - Database error or composite primary key
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let user: Option<User> = User::find(&db, 1).await?;
+    /// ```
+    fn find<'c, C, V>(mut conn: C, id: V) -> BoxFuture<'c, Result<Option<Self>, Error>>
+    where
+        Self: Sized + FromAnyRow + AnyImpl + Send + Sync + Unpin + 'c,
+        C: ModelConnection + 'c,
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+    {
+        Box::pin(async move { conn.model::<Self>().find(id).await })
+    }
 }
 
 // ============================================================================
@@ -574,6 +1001,7 @@ pub trait Model {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dialect::{MySqlDialect, PostgresDialect, SqliteDialect};
 
     #[test]
     fn test_column_info_creation() {
@@ -590,6 +1018,15 @@ mod tests {
             foreign_key: None,
             omit: false,
             soft_delete: false,
+            default: None,
+            check: None,
+            generated: None,
+            stored: false,
+            auto_increment: false,
+            postgres_type: None,
+            mysql_type: None,
+            sqlite_type: None,
+            sensitive: false,
         };
 
         assert_eq!(col.name, "test_column");
@@ -613,9 +1050,56 @@ mod tests {
             foreign_key: Some("id"),
             omit: false,
             soft_delete: false,
+            default: None,
+            check: None,
+            generated: None,
+            stored: false,
+            auto_increment: false,
+            postgres_type: None,
+            mysql_type: None,
+            sqlite_type: None,
+            sensitive: false,
         };
 
         assert_eq!(col.foreign_table, Some("User"));
         assert_eq!(col.foreign_key, Some("id"));
     }
+
+    #[test]
+    fn test_column_info_ddl_type() {
+        let mut col = ColumnInfo {
+            name: "id",
+            sql_type: "UUID",
+            is_primary_key: true,
+            is_nullable: false,
+            create_time: false,
+            update_time: false,
+            unique: false,
+            index: false,
+            foreign_table: None,
+            foreign_key: None,
+            omit: false,
+            soft_delete: false,
+            default: None,
+            check: None,
+            generated: None,
+            stored: false,
+            auto_increment: false,
+            postgres_type: None,
+            mysql_type: None,
+            sqlite_type: None,
+            sensitive: false,
+        };
+
+        assert_eq!(col.ddl_type(&PostgresDialect, true), "UUID");
+        assert_eq!(col.ddl_type(&MySqlDialect, true), "CHAR(36)");
+        assert_eq!(col.ddl_type(&SqliteDialect, true), "TEXT");
+
+        col.sql_type = "TEXT";
+        assert_eq!(col.ddl_type(&MySqlDialect, true), "VARCHAR(255)");
+        assert_eq!(col.ddl_type(&MySqlDialect, false), "TEXT");
+
+        col.mysql_type = Some("VARCHAR(64)");
+        assert_eq!(col.ddl_type(&MySqlDialect, true), "VARCHAR(64)");
+    }
 }
@@ -30,7 +30,13 @@
 //! }
 //! ```
 
-use crate::{any_struct::FromAnyRow, database::Connection, model::Model, query_builder::QueryBuilder, AnyImpl};
+use crate::{
+    any_struct::FromAnyRow,
+    database::Connection,
+    model::Model,
+    query_builder::{CompiledQuery, QueryBuilder},
+    AnyImpl, Error,
+};
 use serde::{Deserialize, Serialize};
 use sqlx::Row;
 
@@ -117,7 +123,7 @@ impl Pagination {
     /// # Returns
     ///
     /// * `Ok(Paginated<R>)` - The paginated results.
-    /// * `Err(sqlx::Error)` - Database error.
+    /// * `Err(Error)` - Database error.
     ///
     /// # Example
     ///
@@ -130,54 +136,47 @@ impl Pagination {
     ///     println!("User: {}", user.username);
     /// }
     /// ```
-    pub async fn paginate<'a, T, E, R>(self, mut query: QueryBuilder<'a, T, E>) -> Result<Paginated<R>, sqlx::Error>
+    pub async fn paginate<'a, T, E, R>(self, mut query: QueryBuilder<'a, T, E>) -> Result<Paginated<R>, Error>
     where
         T: Model + Send + Sync + Unpin,
         E: Connection + Send,
         R: FromAnyRow + AnyImpl + Send + Unpin,
     {
+        // Report invalid builder arguments before running anything
+        query.take_error()?;
+
         // 1. Prepare COUNT query
         // We temporarily replace selected columns with COUNT(*) and remove order/limit/offset
         let original_select = query.select_columns.clone();
+        let original_exprs = query.select_exprs.clone();
         let original_order = query.order_clauses.clone();
         let _original_limit = query.limit;
         let _original_offset = query.offset;
 
-        query.select_columns = vec!["COUNT(*)".to_string()];
         query.order_clauses.clear();
         query.limit = None;
         query.offset = None;
 
-        // 2. Generate and Execute Count SQL
-        // We cannot use query.scalar() easily because it consumes self.
-        // We use query.to_sql() and construct a manual query execution using the builder's state.
-
-        let count_sql = query.to_sql();
-
-        // We need to re-bind arguments. This logic mirrors QueryBuilder::scan
-        let mut args = sqlx::any::AnyArguments::default();
-        let mut arg_counter = 1;
-
-        // Re-bind arguments for count query
-        // Note: We access internal fields of QueryBuilder. This assumes this module is part of the crate.
-        // If WHERE clauses are complex, this manual reconstruction is necessary.
-        let mut dummy_query = String::new(); // Just to satisfy the closure signature
-        for clause in &query.where_clauses {
-            clause(&mut dummy_query, &mut args, &query.driver, &mut arg_counter);
-        }
-        if !query.having_clauses.is_empty() {
-            for clause in &query.having_clauses {
-                clause(&mut dummy_query, &mut args, &query.driver, &mut arg_counter);
-            }
-        }
-
-        // Execute count query
-        let count_row = sqlx::query_with::<_, _>(&count_sql, args).fetch_one(query.tx.executor()).await?;
+        // 2. Compile and Execute Count SQL
+        // We cannot use query.scalar() because it consumes self; build() compiles the
+        // same SQL and arguments (filters, joins, soft delete) without consuming the builder.
+        let count = if query.compounds.is_empty() {
+            query.select_columns = vec!["COUNT(*)".to_string()];
+            query.select_exprs.clear();
+            query.build()
+        } else {
+            // COUNT(*) would only replace the first SELECT of a UNION, so count the combined rows instead
+            let combined = query.build();
+            let sql = format!("SELECT COUNT(*) FROM ({}) AS {}", combined.sql, query.dialect.quote_ident("combined"));
+            CompiledQuery { sql, ..combined }
+        };
+        let count_row = query.fetch_optional_compiled(count).await?.ok_or(sqlx::Error::RowNotFound)?;
 
         let total: i64 = count_row.try_get(0)?;
 
         // 3. Restore Query State for Data Fetch
         query.select_columns = original_select;
+        query.select_exprs = original_exprs;
         query.order_clauses = original_order;
         // Apply Pagination
         query.limit = Some(self.limit);
@@ -49,9 +49,12 @@
 
 use futures::future::BoxFuture;
 use heck::ToSnakeCase;
-use sqlx::{any::AnyArguments, Any, Arguments, Decode, Encode, Row, Type};
-use std::marker::PhantomData;
-use uuid::Uuid;
+use sqlx::{
+    any::{AnyArguments, AnyRow},
+    Any, Arguments, Decode, Encode, Row, Type,
+};
+use sqlx_core::any::AnyValueKind;
+use std::{collections::HashMap, marker::PhantomData, sync::Arc};
 
 // ============================================================================
 // Internal Crate Imports
@@ -59,10 +62,11 @@ use uuid::Uuid;
 
 use crate::{
     any_struct::FromAnyRow,
-    database::{Connection, Drivers},
+    database::Connection,
+    dialect::SqlDialect,
     model::{ColumnInfo, Model},
-    temporal::{self, is_temporal_type},
-    value_binding::ValueBinder,
+    temporal::is_temporal_type,
+    window::Window,
     AnyImpl, Error,
 };
 
@@ -75,25 +79,79 @@ use crate::{
 /// Filter functions receive the following parameters:
 /// 1. `&mut String` - The SQL query buffer being built
 /// 2. `&mut AnyArguments` - The argument container for binding values
-/// 3. `&Drivers` - The current database driver (determines placeholder syntax)
+/// 3. `&dyn SqlDialect` - The active SQL dialect (placeholder syntax and identifier quoting)
 /// 4. `&mut usize` - The argument counter (for PostgreSQL `$n` placeholders)
 ///
 /// ## Example
 ///
 /// ```rust,ignore
-/// let custom_filter: FilterFn = Box::new(|query, args, driver, counter| {
+/// let custom_filter: FilterFn = Box::new(|query, args, dialect, counter| {
 ///     query.push_str(" AND age > ");
-///     match driver {
-///         Drivers::Postgres => {
-///             query.push_str(&format!("${}", counter));
-///             *counter += 1;
-///         }
-///         _ => query.push('?'),
-///     }
+///     query.push_str(&dialect.placeholder(*counter));
+///     *counter += 1;
 ///     args.add(18);
 /// });
-/// });\n/// ```
-pub type FilterFn = Box<dyn Fn(&mut String, &mut AnyArguments<'_>, &Drivers, &mut usize) + Send + Sync>;
+/// ```
+pub type FilterFn = Box<dyn Fn(&mut String, &mut AnyArguments<'_>, &dyn SqlDialect, &mut usize) + Send + Sync>;
+
+// ============================================================================
+// Compiled Query
+// ============================================================================
+
+/// Literal shown in place of values bound for `#[orm(sensitive)]` columns.
+const REDACTED: &str = "'[REDACTED]'";
+
+/// A fully rendered SQL statement together with its bound arguments.
+///
+/// Produced by [`QueryBuilder::build()`] and [`QueryBuilder::build_for()`]. Every
+/// execution path (`scan`, `first`, `scalar`, `to_sql` and `Pagination::paginate`)
+/// goes through the same compiler. `build_for::<R>()` returns exactly what
+/// `scan::<R>()` sends to the database; `build()` uses the model's own projection.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let compiled = db.model::<User>().filter("age", Op::Gte, 18).limit(10).build();
+///
+/// println!("{}", compiled.sql); // SELECT * FROM "user" WHERE 1=1 AND "age" >= $1 LIMIT $2
+/// assert_eq!(compiled.len(), 2);
+///
+/// let mut conn = db.clone();
+/// let rows = sqlx::query_with(&compiled.sql, compiled.args).fetch_all(conn.executor()).await?;
+/// ```
+pub struct CompiledQuery {
+    /// The SQL statement, with dialect-specific placeholders
+    pub sql: String,
+
+    /// The values bound to the placeholders, in order
+    pub args: AnyArguments<'static>,
+
+    /// Positions in `args` of values bound for `#[orm(sensitive)]` columns
+    pub(crate) redacted: Vec<usize>,
+}
+
+impl CompiledQuery {
+    /// Returns `true` if the argument at `index` belongs to an `#[orm(sensitive)]` column.
+    pub fn is_redacted(&self, index: usize) -> bool {
+        self.redacted.contains(&index)
+    }
+
+    /// Returns the number of bound arguments.
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Returns `true` if the statement has no bound arguments.
+    pub fn is_empty(&self) -> bool {
+        self.args.len() == 0
+    }
+}
+
+impl std::fmt::Debug for CompiledQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CompiledQuery").field("sql", &self.sql).field("args", &self.args.len()).finish()
+    }
+}
 
 // ============================================================================
 // Comparison Operators Enum
@@ -156,6 +214,206 @@ impl Op {
     }
 }
 
+// ============================================================================
+// Ordering Types
+// ============================================================================
+
+/// Sort direction for [`QueryBuilder::order_by()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Ascending: `ASC`
+    Asc,
+    /// Descending: `DESC`
+    Desc,
+}
+
+impl Direction {
+    /// Converts the direction to its SQL keyword.
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            Direction::Asc => "ASC",
+            Direction::Desc => "DESC",
+        }
+    }
+}
+
+/// Placement of NULL values in an ordered result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Nulls {
+    /// NULL values sort before all other values
+    First,
+    /// NULL values sort after all other values
+    Last,
+}
+
+// ============================================================================
+// Row Locking Types
+// ============================================================================
+
+/// Row lock taken by a SELECT (see [`QueryBuilder::lock_for_update()`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockMode {
+    /// `FOR UPDATE` - exclusive lock, for rows that will be modified
+    Update,
+    /// `FOR SHARE` - shared lock, blocks writers but not other readers
+    Share,
+}
+
+/// Behavior when a row is already locked by another transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LockWait {
+    /// Wait until the lock is released
+    #[default]
+    Wait,
+    /// `NOWAIT` - fail immediately
+    NoWait,
+    /// `SKIP LOCKED` - leave locked rows out of the result
+    SkipLocked,
+}
+
+// ============================================================================
+// SQL Fragments
+// ============================================================================
+
+/// Input accepted by `select`, `group_by`, `order` and `having`.
+///
+/// Plain strings convert to [`Fragment::Ident`], a list of column names. In
+/// strict mode (see [`QueryBuilder::strict()`]) every name is validated against
+/// the model; anything else must be wrapped with [`raw()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fragment {
+    /// Column names, validated in strict mode
+    Ident(String),
+    /// SQL spliced into the query verbatim
+    Raw(String),
+}
+
+impl From<&str> for Fragment {
+    fn from(value: &str) -> Self {
+        Fragment::Ident(value.to_string())
+    }
+}
+
+impl From<String> for Fragment {
+    fn from(value: String) -> Self {
+        Fragment::Ident(value)
+    }
+}
+
+impl From<&String> for Fragment {
+    fn from(value: &String) -> Self {
+        Fragment::Ident(value.clone())
+    }
+}
+
+/// Marks an SQL expression as trusted, bypassing strict identifier validation.
+///
+/// The text is spliced into the query verbatim, so it must never contain
+/// untrusted input.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use bottle_orm::raw;
+///
+/// let stats: Vec<(i32, i64)> = db.model::<User>()
+///     .strict()
+///     .select("age")
+///     .select(raw("COUNT(*)"))
+///     .group_by("age")
+///     .having(raw("COUNT(*)"), ">", 5)
+///     .scan()
+///     .await?;
+/// ```
+pub fn raw(sql: impl Into<String>) -> Fragment {
+    Fragment::Raw(sql.into())
+}
+
+/// Expression accepted by [`QueryBuilder::select_expr()`].
+///
+/// Built from a [`Window`] or a [`Fragment`]: a plain string is a column
+/// reference (validated in strict mode) and [`raw()`] is spliced verbatim.
+#[derive(Debug, Clone)]
+pub enum Expression {
+    /// Window function expression
+    Window(Window),
+    /// Column reference or raw SQL
+    Fragment(Fragment),
+}
+
+impl From<Window> for Expression {
+    fn from(value: Window) -> Self {
+        Expression::Window(value)
+    }
+}
+
+impl<F: Into<Fragment>> From<F> for Expression {
+    fn from(value: F) -> Self {
+        Expression::Fragment(value.into())
+    }
+}
+
+/// Comparison operators accepted by `having()` in strict mode.
+const STRICT_OPERATORS: &[&str] = &["=", "!=", "<>", "<", "<=", ">", ">=", "LIKE", "NOT LIKE"];
+
+/// A single ORDER BY entry.
+#[derive(Debug, Clone)]
+pub(crate) enum OrderClause {
+    /// Expression added with `order()`, spliced verbatim
+    Raw(String),
+    /// Column added with `order_by()`, already quoted
+    Column { column: String, direction: Direction, nulls: Option<Nulls> },
+}
+
+/// A common table expression added with `with_cte()` or `with_recursive()`.
+#[derive(Debug, Clone)]
+pub(crate) struct CommonTableExpression {
+    /// CTE name (unquoted)
+    pub(crate) name: String,
+    /// Compiled body, with placeholders numbered from 1
+    pub(crate) body: String,
+    /// Values bound by the body, in placeholder order
+    pub(crate) values: Vec<AnyValueKind<'static>>,
+    /// Indexes into `values` that must be redacted when logged
+    pub(crate) redacted: Vec<usize>,
+    /// Whether the body references the CTE itself
+    pub(crate) recursive: bool,
+}
+
+/// Set operation combining two SELECT statements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SetOperator {
+    Union,
+    UnionAll,
+    Intersect,
+    Except,
+}
+
+impl SetOperator {
+    /// Converts the operator to its SQL representation.
+    pub(crate) fn as_sql(&self) -> &'static str {
+        match self {
+            SetOperator::Union => "UNION",
+            SetOperator::UnionAll => "UNION ALL",
+            SetOperator::Intersect => "INTERSECT",
+            SetOperator::Except => "EXCEPT",
+        }
+    }
+}
+
+/// A query combined with `union()`, `union_all()`, `intersect()` or `except()`.
+#[derive(Debug, Clone)]
+pub(crate) struct CompoundQuery {
+    /// Operator placed before the query
+    pub(crate) operator: SetOperator,
+    /// Compiled query, with placeholders numbered from 1
+    pub(crate) body: String,
+    /// Values bound by the query, in placeholder order
+    pub(crate) values: Vec<AnyValueKind<'static>>,
+    /// Indexes into `values` that must be redacted when logged
+    pub(crate) redacted: Vec<usize>,
+}
+
 // ============================================================================
 // QueryBuilder Struct
 // ============================================================================
@@ -188,8 +446,8 @@ pub struct QueryBuilder<'a, T, E> {
     /// Reference to the database connection pool
     pub(crate) tx: E,
 
-    /// Database driver type
-    pub(crate) driver: Drivers,
+    /// SQL dialect used to render placeholders, identifiers and clauses
+    pub(crate) dialect: Arc<dyn SqlDialect>,
 
     /// Name of the database table (in original case)
     pub(crate) table_name: &'static str,
@@ -198,16 +456,47 @@ pub struct QueryBuilder<'a, T, E> {
     pub(crate) columns_info: Vec<ColumnInfo>,
 
     /// List of column names (in snake_case)
+    #[allow(dead_code)]
     pub(crate) columns: Vec<String>,
 
     /// Specific columns to select (empty means SELECT *)
     pub(crate) select_columns: Vec<String>,
 
+    /// Rendered expressions added with `select_expr()`, paired with their aliases
+    pub(crate) select_exprs: Vec<(String, String)>,
+
     /// Collection of WHERE clause filter functions
     pub(crate) where_clauses: Vec<FilterFn>,
 
+    /// Indexes into `where_clauses` of filters on `#[orm(sensitive)]` columns
+    pub(crate) sensitive_filters: Vec<usize>,
+
     /// Collection of ORDER BY clauses
-    pub(crate) order_clauses: Vec<String>,
+    pub(crate) order_clauses: Vec<OrderClause>,
+
+    /// Common table expressions rendered in the `WITH` clause, in declaration order
+    pub(crate) ctes: Vec<CommonTableExpression>,
+
+    /// Queries combined with this one by set operators, in call order
+    pub(crate) compounds: Vec<CompoundQuery>,
+
+    /// Tables added with `join()` and its variants (snake_case, unquoted)
+    pub(crate) joined_tables: Vec<String>,
+
+    /// Column names of tables joined with `join_model()`, keyed by table name
+    pub(crate) joined_columns: Vec<(String, Vec<String>)>,
+
+    /// Whether identifiers passed to builder methods are validated
+    pub(crate) strict: bool,
+
+    /// Row lock taken by SELECT statements
+    pub(crate) lock: Option<LockMode>,
+
+    /// Behavior when selected rows are already locked
+    pub(crate) lock_wait: LockWait,
+
+    /// First invalid argument passed to a builder method, reported on execution
+    pub(crate) build_error: Option<Error>,
 
     /// Collection of JOIN clause to filter entry tables
     pub(crate) joins_clauses: Vec<String>,
@@ -261,6 +550,7 @@ where
     /// # Arguments
     ///
     /// * `db` - Reference to the database connection
+    /// * `dialect` - SQL dialect used to render the query
     /// * `table_name` - Name of the table to query
     /// * `columns_info` - Metadata about table columns
     /// * `columns` - List of column names
@@ -277,7 +567,7 @@ where
     /// ```
     pub fn new(
         tx: E,
-        driver: Drivers,
+        dialect: Arc<dyn SqlDialect>,
         table_name: &'static str,
         columns_info: Vec<ColumnInfo>,
         columns: Vec<String>,
@@ -288,14 +578,24 @@ where
 
         Self {
             tx,
-            driver,
+            dialect,
             table_name,
             columns_info,
             columns,
             debug_mode: false,
             select_columns: Vec::new(),
+            select_exprs: Vec::new(),
             where_clauses: Vec::new(),
+            sensitive_filters: Vec::new(),
             order_clauses: Vec::new(),
+            ctes: Vec::new(),
+            compounds: Vec::new(),
+            joined_tables: Vec::new(),
+            joined_columns: Vec::new(),
+            strict: false,
+            lock: None,
+            lock_wait: LockWait::Wait,
+            build_error: None,
             joins_clauses: Vec::new(),
             group_by_clauses: Vec::new(),
             having_clauses: Vec::new(),
@@ -362,32 +662,26 @@ where
         V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
     {
         let op_str = op.as_sql();
-        let clause: FilterFn = Box::new(move |query, args, driver, arg_counter| {
+        let clause: FilterFn = Box::new(move |query, args, dialect, arg_counter| {
             query.push_str(" AND ");
-            if let Some((table, column)) = col.split_once(".") {
-                query.push_str(&format!("\"{}\".\"{}\"", table, column));
-            } else {
-                query.push_str(&format!("\"{}\"", col));
-            }
+            query.push_str(&dialect.quote_column(col));
             query.push(' ');
             query.push_str(op_str);
             query.push(' ');
 
-            // Handle different placeholder syntaxes based on database driver
-            match driver {
-                // PostgreSQL uses numbered placeholders: $1, $2, $3, ...
-                Drivers::Postgres => {
-                    query.push_str(&format!("${}", arg_counter));
-                    *arg_counter += 1;
-                }
-                // MySQL and SQLite use question mark placeholders: ?
-                _ => query.push('?'),
-            }
+            // Placeholder syntax is dialect-specific ($n for PostgreSQL, ? elsewhere)
+            query.push_str(&dialect.placeholder(*arg_counter));
+            *arg_counter += 1;
 
             // Bind the value to the query
             let _ = args.add(value.clone());
         });
 
+        // Remember filters whose bound value must be hidden from debug output
+        if self.is_sensitive(col) {
+            self.sensitive_filters.push(self.where_clauses.len());
+        }
+
         self.where_clauses.push(clause);
         self
     }
@@ -426,7 +720,8 @@ where
     ///
     /// # Arguments
     ///
-    /// * `order` - The ORDER BY expression (e.g., "created_at DESC", "age ASC, name DESC")
+    /// * `order` - The ORDER BY expression (e.g., "created_at DESC", "age ASC, name DESC").
+    ///   In strict mode each entry must be `column [ASC|DESC]`; wrap other expressions with `raw()`.
     ///
     /// # Example
     ///
@@ -445,8 +740,93 @@ where
     ///     .order("priority DESC")
     ///     .order("created_at ASC")
     /// ```
-    pub fn order(mut self, order: &str) -> Self {
-        self.order_clauses.push(order.to_string());
+    pub fn order(mut self, order: impl Into<Fragment>) -> Self {
+        let order = match order.into() {
+            Fragment::Ident(order) if self.strict => order,
+            Fragment::Ident(order) | Fragment::Raw(order) => {
+                self.order_clauses.push(OrderClause::Raw(order));
+                return self;
+            }
+        };
+
+        for entry in order.split(',') {
+            let mut parts = entry.split_whitespace();
+            let column = parts.next().unwrap_or_default();
+            let direction = match parts.next().map(|d| d.to_ascii_uppercase()).as_deref() {
+                None | Some("ASC") => Direction::Asc,
+                Some("DESC") => Direction::Desc,
+                Some(other) => {
+                    self.set_error(Error::InvalidArgument(format!("invalid sort direction `{}`", other)));
+                    return self;
+                }
+            };
+            if parts.next().is_some() {
+                self.set_error(Error::InvalidArgument(format!("invalid ORDER BY entry `{}`", entry.trim())));
+                return self;
+            }
+            self = self.order_by(column, direction);
+        }
+        self
+    }
+
+    /// Adds a validated ORDER BY entry for a single column.
+    ///
+    /// Unlike `order()`, the column is checked against the model's columns (or a
+    /// joined table when qualified as `table.column`) and quoted, so it is safe to
+    /// drive from user input. An unknown column makes the query fail with
+    /// `Error::InvalidArgument` when it is executed.
+    ///
+    /// Combine with `nulls_first()` / `nulls_last()` to control where NULL values go.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The column name, optionally qualified with a table name
+    /// * `direction` - `Direction::Asc` or `Direction::Desc`
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use bottle_orm::Direction;
+    ///
+    /// // ORDER BY "last_login" DESC NULLS LAST, "id" ASC
+    /// let users: Vec<User> = db.model::<User>()
+    ///     .order_by("last_login", Direction::Desc)
+    ///     .nulls_last()
+    ///     .order_by("id", Direction::Asc)
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn order_by(mut self, col: &str, direction: Direction) -> Self {
+        match self.resolve_column(col) {
+            Ok(column) => self.order_clauses.push(OrderClause::Column { column, direction, nulls: None }),
+            Err(e) => self.set_error(e),
+        }
+        self
+    }
+
+    /// Sorts NULL values first for the most recent `order_by()` entry.
+    ///
+    /// Rendered as `NULLS FIRST` on PostgreSQL and SQLite and emulated with an
+    /// `IS NULL` sort key on MySQL. Has no effect if the last ORDER BY entry was
+    /// not added with `order_by()`.
+    pub fn nulls_first(self) -> Self {
+        self.set_nulls(Nulls::First)
+    }
+
+    /// Sorts NULL values last for the most recent `order_by()` entry.
+    ///
+    /// Rendered as `NULLS LAST` on PostgreSQL and SQLite and emulated with an
+    /// `IS NULL` sort key on MySQL. Has no effect if the last ORDER BY entry was
+    /// not added with `order_by()`.
+    pub fn nulls_last(self) -> Self {
+        self.set_nulls(Nulls::Last)
+    }
+
+    /// Sets the NULL placement of the last structured ORDER BY entry.
+    fn set_nulls(mut self, placement: Nulls) -> Self {
+        if let Some(OrderClause::Column { nulls, .. }) = self.order_clauses.last_mut() {
+            *nulls = Some(placement);
+        }
         self
     }
 
@@ -463,15 +843,17 @@ where
     /// // Future usage example
     /// query.preload("posts").preload("comments")
     /// ```
-    // pub fn preload(self) -> Self {
-    //     // TODO: Implement relationship preloading
-    //     self
-    // }
+    #[cfg(any())]
+    pub fn preload(self) -> Self {
+        // TODO: Implement relationship preloading
+        self
+    }
 
     /// Activates debug mode for this query.
     ///
-    /// When enabled, the generated SQL query will be logged using the `log` crate
-    /// at the `DEBUG` level before execution.
+    /// When enabled, the generated SQL query and its bound values will be logged
+    /// using the `log` crate at the `DEBUG` level before execution. Values of
+    /// `#[orm(sensitive)]` columns are redacted.
     ///
     /// # Note
     ///
@@ -483,7 +865,7 @@ where
     /// ```rust,ignore
     /// db.model::<User>()
     ///     .filter("active", "=", true)
-    ///     .debug() // Logs SQL: SELECT * FROM "user" WHERE "active" = $1
+    ///     .debug() // Logs: SQL: SELECT * FROM "user" WHERE 1=1 AND "active" = $1 -- params: [TRUE]
     ///     .scan()
     ///     .await?;
     /// ```
@@ -509,13 +891,9 @@ where
     /// ```
     pub fn is_null(mut self, col: &str) -> Self {
         let col_owned = col.to_string();
-        let clause: FilterFn = Box::new(move |query, _args, _driver, _arg_counter| {
+        let clause: FilterFn = Box::new(move |query, _args, dialect, _arg_counter| {
             query.push_str(" AND ");
-            if let Some((table, column)) = col_owned.split_once(".") {
-                query.push_str(&format!("\"{}\".\"{}\"", table, column));
-            } else {
-                query.push_str(&format!("\"{}\"", col_owned));
-            }
+            query.push_str(&dialect.quote_column(&col_owned));
             query.push_str(" IS NULL");
         });
         self.where_clauses.push(clause);
@@ -539,19 +917,125 @@ where
     /// ```
     pub fn is_not_null(mut self, col: &str) -> Self {
         let col_owned = col.to_string();
-        let clause: FilterFn = Box::new(move |query, _args, _driver, _arg_counter| {
+        let clause: FilterFn = Box::new(move |query, _args, dialect, _arg_counter| {
             query.push_str(" AND ");
-            if let Some((table, column)) = col_owned.split_once(".") {
-                query.push_str(&format!("\"{}\".\"{}\"", table, column));
-            } else {
-                query.push_str(&format!("\"{}\"", col_owned));
-            }
+            query.push_str(&dialect.quote_column(&col_owned));
             query.push_str(" IS NOT NULL");
         });
         self.where_clauses.push(clause);
         self
     }
 
+    /// Enables strict identifier validation for this query.
+    ///
+    /// In strict mode, column names passed to `select`, `group_by`, `order`,
+    /// `having`, the aggregate helpers and the `ON` clause of joins must be
+    /// columns of the model, of a model joined with `join_model()`, or plain
+    /// identifiers qualified with a table joined with `join()`. Invalid input is
+    /// never interpolated: the query fails with `Error::InvalidArgument` when it
+    /// is executed. Expressions must be passed explicitly with [`raw()`].
+    ///
+    /// Use this whenever sort or field parameters come from an HTTP request.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // `sort` comes from the query string, e.g. "created_at desc"
+    /// let users: Vec<User> = db.model::<User>()
+    ///     .strict()
+    ///     .order(sort.as_str())
+    ///     .scan()
+    ///     .await?; // Err(InvalidArgument) for "id; DROP TABLE user"
+    /// ```
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Locks the selected rows for update (`SELECT ... FOR UPDATE`).
+    ///
+    /// Other transactions cannot modify or lock the rows until this transaction
+    /// ends. Only allowed on a transaction connection (`tx.model::<T>()`); on a
+    /// pool the query fails with `Error::InvalidArgument`.
+    ///
+    /// SQLite has no row locks: the transaction takes the database write lock
+    /// instead (as if started with `BEGIN IMMEDIATE`), and `skip_locked()` /
+    /// `nowait()` have no effect.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut tx = db.begin().await?;
+    /// let account = tx.model::<Account>()
+    ///     .filter("id", Op::Eq, 1)
+    ///     .lock_for_update()
+    ///     .first::<Account>()
+    ///     .await?;
+    /// tx.model::<Account>().filter("id", Op::Eq, 1).update("balance", account.balance - 100).await?;
+    /// tx.commit().await?;
+    /// ```
+    pub fn lock_for_update(self) -> Self {
+        self.set_lock(LockMode::Update)
+    }
+
+    /// Locks the selected rows against modification (`SELECT ... FOR SHARE`).
+    ///
+    /// Other transactions can still read and share-lock the rows. See
+    /// [`lock_for_update()`](Self::lock_for_update) for requirements.
+    pub fn lock_for_share(self) -> Self {
+        self.set_lock(LockMode::Share)
+    }
+
+    /// Skips rows locked by other transactions (`SKIP LOCKED`).
+    ///
+    /// Must follow `lock_for_update()` or `lock_for_share()`. Typically used to
+    /// let several workers claim jobs from the same queue.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let jobs: Vec<Job> = tx.model::<Job>()
+    ///     .filter("status", Op::Eq, "pending".to_string())
+    ///     .order("id")
+    ///     .limit(10)
+    ///     .lock_for_update()
+    ///     .skip_locked()
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn skip_locked(self) -> Self {
+        self.set_lock_wait(LockWait::SkipLocked)
+    }
+
+    /// Fails immediately instead of waiting for locked rows (`NOWAIT`).
+    ///
+    /// Must follow `lock_for_update()` or `lock_for_share()`.
+    pub fn nowait(self) -> Self {
+        self.set_lock_wait(LockWait::NoWait)
+    }
+
+    /// Records the row lock, rejecting it outside a transaction.
+    fn set_lock(mut self, mode: LockMode) -> Self {
+        if !E::IS_TRANSACTION {
+            self.set_error(Error::InvalidArgument("row locks require a transaction connection".to_string()));
+            return self;
+        }
+        self.lock = Some(mode);
+        self
+    }
+
+    /// Records the lock wait behavior, which requires a row lock.
+    fn set_lock_wait(mut self, wait: LockWait) -> Self {
+        if self.lock.is_none() {
+            self.set_error(Error::InvalidArgument(
+                "skip_locked() and nowait() require lock_for_update() or lock_for_share()".to_string(),
+            ));
+            return self;
+        }
+        self.lock_wait = wait;
+        self
+    }
+
     /// Includes soft-deleted records in query results.
     ///
     /// By default, queries on models with a `#[orm(soft_delete)]` column exclude
@@ -593,36 +1077,72 @@ where
     /// ```rust,ignore
     /// query.join("posts", "users.id = posts.user_id")
     /// ```
-    pub fn join(mut self, table: &str, s_query: &str) -> Self {
-        let trimmed_value = s_query.replace(" ", "");
-        let values = trimmed_value.split_once("=");
-        let parsed_query: String;
-        if let Some((first, second)) = values {
-            let ref_table = first.split_once(".").expect("failed to parse JOIN clause");
-            let to_table = second.split_once(".").expect("failed to parse JOIN clause");
-            parsed_query = format!("\"{}\".\"{}\" = \"{}\".\"{}\"", ref_table.0, ref_table.1, to_table.0, to_table.1);
-        } else {
-            panic!("Failed to parse JOIN, Ex to use: .join(\"table2\", \"table.column = table2.column2\")")
-        }
+    pub fn join(self, table: &str, s_query: &str) -> Self {
+        self.join_generic("", table, s_query)
+    }
 
-        self.joins_clauses.push(format!("JOIN \"{}\" ON {}", table, parsed_query));
-        self
+    /// Adds an INNER JOIN with another model, registering its columns for strict validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `on` - The join condition (e.g., "user.id = post.user_id")
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let rows: Vec<(User, Post)> = db.model::<User>()
+    ///     .strict()
+    ///     .join_model::<Post>("user.id = post.user_id")
+    ///     .order("post.created_at DESC")
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn join_model<M: Model>(mut self, on: &str) -> Self {
+        let table = M::table_name().to_snake_case();
+        let columns = M::columns().iter().map(|c| c.name.strip_prefix("r#").unwrap_or(c.name).to_snake_case()).collect();
+        self.joined_columns.push((table.clone(), columns));
+        self.join_generic("", &table, on)
     }
 
     /// Internal helper for specific join types
     fn join_generic(mut self, join_type: &str, table: &str, s_query: &str) -> Self {
         let trimmed_value = s_query.replace(" ", "");
-        let values = trimmed_value.split_once("=");
-        let parsed_query: String;
-        if let Some((first, second)) = values {
-            let ref_table = first.split_once(".").expect("failed to parse JOIN clause");
-            let to_table = second.split_once(".").expect("failed to parse JOIN clause");
-            parsed_query = format!("\"{}\".\"{}\" = \"{}\".\"{}\"", ref_table.0, ref_table.1, to_table.0, to_table.1);
-        } else {
-            panic!("Failed to parse JOIN, Ex to use: .join(\"table2\", \"table.column = table2.column2\")")
+        let sides = trimmed_value
+            .split_once("=")
+            .and_then(|(first, second)| Some((first.split_once(".")?, second.split_once(".")?)));
+        let Some((ref_table, to_table)) = sides else {
+            self.set_error(Error::invalid_argument(&format!(
+                "failed to parse JOIN condition `{}`, expected `table.column = table2.column2`",
+                s_query
+            )));
+            return self;
+        };
+        let parsed_query = format!(
+            "{}.{} = {}.{}",
+            self.dialect.quote_ident(ref_table.0),
+            self.dialect.quote_ident(ref_table.1),
+            self.dialect.quote_ident(to_table.0),
+            self.dialect.quote_ident(to_table.1)
+        );
+
+        if self.strict && !is_identifier(table) {
+            self.set_error(Error::InvalidArgument(format!("invalid join table `{}`", table)));
+            return self;
+        }
+        self.joined_tables.push(table.to_snake_case());
+
+        // Both sides of the ON condition must reference known columns
+        if self.strict {
+            for side in trimmed_value.split('=') {
+                if let Err(e) = self.resolve_column(side) {
+                    self.set_error(e);
+                    return self;
+                }
+            }
         }
 
-        self.joins_clauses.push(format!("{} JOIN \"{}\" ON {}", join_type, table, parsed_query));
+        let join_keyword = if join_type.is_empty() { "JOIN".to_string() } else { format!("{} JOIN", join_type) };
+        self.joins_clauses.push(format!("{} {} ON {}", join_keyword, self.dialect.quote_ident(table), parsed_query));
         self
     }
 
@@ -712,58 +1232,277 @@ where
         self.join_generic("FULL", table, on)
     }
 
-    /// Marks the query to return DISTINCT results.
+    /// Adds a common table expression (`WITH name AS (...)`) to the query.
     ///
-    /// Adds the `DISTINCT` keyword to the SELECT statement, ensuring that unique
-    /// rows are returned.
+    /// The sub-query is compiled immediately; its bound values are merged into
+    /// this query and its placeholders renumbered for the active dialect. The CTE
+    /// can then be referenced as a table, e.g. with `join()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The CTE name (must be a plain identifier)
+    /// * `sub_query` - The query defining the CTE
     ///
     /// # Example
     ///
     /// ```rust,ignore
-    /// // Get unique ages of users
-    /// let unique_ages: Vec<i32> = db.model::<User>()
-    ///     .select("age")
-    ///     .distinct()
+    /// // WITH "big_spenders" AS (SELECT "user_id" FROM "order" WHERE 1=1 AND "total" > $1)
+    /// // SELECT ... FROM "user" JOIN "big_spenders" ON ...
+    /// let users: Vec<User> = db.model::<User>()
+    ///     .with_cte("big_spenders", db.model::<Order>().select("user_id").filter("total", Op::Gt, 1000))
+    ///     .join("big_spenders", "big_spenders.user_id = user.id")
     ///     .scan()
     ///     .await?;
     /// ```
-    pub fn distinct(mut self) -> Self {
-        self.is_distinct = true;
+    pub fn with_cte<M, C>(mut self, name: &str, sub_query: QueryBuilder<'_, M, C>) -> Self
+    where
+        M: Model + Send + Sync + Unpin,
+        C: Connection + Send,
+    {
+        if let Some(compiled) = self.compile_cte_part(name, sub_query) {
+            self.ctes.push(CommonTableExpression {
+                name: name.to_string(),
+                body: compiled.sql,
+                values: compiled.args.values.0,
+                redacted: compiled.redacted,
+                recursive: false,
+            });
+        }
         self
     }
 
-    /// Adds a GROUP BY clause to the query.
+    /// Adds a recursive common table expression (`WITH RECURSIVE name AS (anchor UNION ALL recursive)`).
     ///
-    /// Groups rows that have the same values into summary rows. Often used with
-    /// aggregate functions (COUNT, MAX, MIN, SUM, AVG).
+    /// The recursive part references the CTE by `name`, typically through a join.
+    /// Both parts must select the same number of columns.
     ///
     /// # Arguments
     ///
-    /// * `columns` - Comma-separated list of columns to group by
+    /// * `name` - The CTE name (must be a plain identifier)
+    /// * `anchor` - The non-recursive starting rows
+    /// * `recursive` - The query producing further rows from the CTE itself
     ///
     /// # Example
     ///
     /// ```rust,ignore
-    /// // Count users by age group
-    /// let stats: Vec<(i32, i64)> = db.model::<User>()
-    ///     .select("age, COUNT(*)")
-    ///     .group_by("age")
+    /// use bottle_orm::{raw, Op};
+    ///
+    /// // Category 1 and all of its descendants
+    /// let subtree: Vec<Category> = db.model::<Category>()
+    ///     .with_recursive(
+    ///         "tree",
+    ///         db.model::<Category>().filter("id", Op::Eq, 1),
+    ///         db.model::<Category>().select(raw("category.*")).join("tree", "tree.id = category.parent_id"),
+    ///     )
+    ///     .join("tree", "tree.id = category.id")
     ///     .scan()
     ///     .await?;
     /// ```
-    pub fn group_by(mut self, columns: &str) -> Self {
-        self.group_by_clauses.push(columns.to_string());
+    pub fn with_recursive<A, CA, R, CR>(
+        mut self,
+        name: &str,
+        anchor: QueryBuilder<'_, A, CA>,
+        recursive: QueryBuilder<'_, R, CR>,
+    ) -> Self
+    where
+        A: Model + Send + Sync + Unpin,
+        CA: Connection + Send,
+        R: Model + Send + Sync + Unpin,
+        CR: Connection + Send,
+    {
+        let anchor = self.compile_cte_part(name, anchor);
+        let recursive = self.compile_cte_part(name, recursive);
+        if let (Some(anchor), Some(recursive)) = (anchor, recursive) {
+            let offset = anchor.len();
+            let body = format!(
+                "{} UNION ALL {}",
+                anchor.sql,
+                self.renumber_placeholders(&recursive.sql, recursive.len(), offset)
+            );
+            let mut redacted = anchor.redacted;
+            redacted.extend(recursive.redacted.iter().map(|i| i + offset));
+            let mut values = anchor.args.values.0;
+            values.extend(recursive.args.values.0);
+
+            self.ctes.push(CommonTableExpression { name: name.to_string(), body, values, redacted, recursive: true });
+        }
         self
     }
 
-    /// Adds a HAVING clause to the query.
-    ///
-    /// Used to filter groups created by `group_by`. Similar to `filter` (WHERE),
-    /// but operates on grouped records and aggregate functions.
-    ///
-    /// # Arguments
+    /// Validates a CTE name and compiles one of its queries, recording the first error.
+    fn compile_cte_part<M, C>(&mut self, name: &str, mut sub_query: QueryBuilder<'_, M, C>) -> Option<CompiledQuery>
+    where
+        M: Model + Send + Sync + Unpin,
+        C: Connection + Send,
+    {
+        if !is_identifier(name) {
+            self.set_error(Error::InvalidArgument(format!("invalid CTE name `{}`", name)));
+            return None;
+        }
+        if let Some(e) = sub_query.build_error.take() {
+            self.set_error(e);
+            return None;
+        }
+        Some(sub_query.build())
+    }
+
+    /// Combines the results with another query, removing duplicate rows (`UNION`).
+    ///
+    /// Both queries must select the same number of compatible columns, in the same
+    /// order. `order()`, `order_by()`, `limit()` and `offset()` set on this query
+    /// apply to the combined result; the other query must not set them.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use bottle_orm::{raw, FromAnyRow};
+    ///
+    /// #[derive(FromAnyRow)]
+    /// struct Activity {
+    ///     id: i32,
+    ///     body: String,
+    ///     kind: String,
+    /// }
+    ///
+    /// // Latest posts and comments in a single feed
+    /// let feed: Vec<Activity> = db.model::<Post>()
+    ///     .select("id")
+    ///     .select("body")
+    ///     .select_expr(raw("'post'"), "kind")
+    ///     .union_all(db.model::<Comment>().select("id").select("body").select_expr(raw("'comment'"), "kind"))
+    ///     .order("id DESC")
+    ///     .limit(20)
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn union<M, C>(self, other: QueryBuilder<'_, M, C>) -> Self
+    where
+        M: Model + Send + Sync + Unpin,
+        C: Connection + Send,
+    {
+        self.combine(SetOperator::Union, other)
+    }
+
+    /// Combines the results with another query, keeping duplicate rows (`UNION ALL`).
+    ///
+    /// See [`union()`](Self::union) for the requirements on both queries.
+    pub fn union_all<M, C>(self, other: QueryBuilder<'_, M, C>) -> Self
+    where
+        M: Model + Send + Sync + Unpin,
+        C: Connection + Send,
+    {
+        self.combine(SetOperator::UnionAll, other)
+    }
+
+    /// Keeps only the rows also returned by another query (`INTERSECT`).
+    ///
+    /// See [`union()`](Self::union) for the requirements on both queries.
+    pub fn intersect<M, C>(self, other: QueryBuilder<'_, M, C>) -> Self
+    where
+        M: Model + Send + Sync + Unpin,
+        C: Connection + Send,
+    {
+        self.combine(SetOperator::Intersect, other)
+    }
+
+    /// Removes the rows returned by another query (`EXCEPT`).
+    ///
+    /// See [`union()`](Self::union) for the requirements on both queries.
+    pub fn except<M, C>(self, other: QueryBuilder<'_, M, C>) -> Self
+    where
+        M: Model + Send + Sync + Unpin,
+        C: Connection + Send,
+    {
+        self.combine(SetOperator::Except, other)
+    }
+
+    /// Compiles `other` and appends it with the given set operator.
+    fn combine<M, C>(mut self, operator: SetOperator, mut other: QueryBuilder<'_, M, C>) -> Self
+    where
+        M: Model + Send + Sync + Unpin,
+        C: Connection + Send,
+    {
+        if let Some(e) = other.build_error.take() {
+            self.set_error(e);
+            return self;
+        }
+        if !other.order_clauses.is_empty() || other.limit.is_some() || other.offset.is_some() {
+            self.set_error(Error::InvalidArgument(format!(
+                "{} query cannot be ordered or limited; set ORDER BY and LIMIT on the outer query",
+                operator.as_sql()
+            )));
+            return self;
+        }
+
+        let compiled = other.build();
+        self.compounds.push(CompoundQuery {
+            operator,
+            body: compiled.sql,
+            values: compiled.args.values.0,
+            redacted: compiled.redacted,
+        });
+        self
+    }
+
+    /// Marks the query to return DISTINCT results.
+    ///
+    /// Adds the `DISTINCT` keyword to the SELECT statement, ensuring that unique
+    /// rows are returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Get unique ages of users
+    /// let unique_ages: Vec<i32> = db.model::<User>()
+    ///     .select("age")
+    ///     .distinct()
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn distinct(mut self) -> Self {
+        self.is_distinct = true;
+        self
+    }
+
+    /// Adds a GROUP BY clause to the query.
+    ///
+    /// Groups rows that have the same values into summary rows. Often used with
+    /// aggregate functions (COUNT, MAX, MIN, SUM, AVG).
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - Comma-separated list of columns to group by (validated in strict mode)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Count users by age group
+    /// let stats: Vec<(i32, i64)> = db.model::<User>()
+    ///     .select("age, COUNT(*)")
+    ///     .group_by("age")
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn group_by(mut self, columns: impl Into<Fragment>) -> Self {
+        match columns.into() {
+            Fragment::Ident(columns) if self.strict => {
+                if let Some(quoted) = self.resolve_columns(&columns) {
+                    self.group_by_clauses.push(quoted.join(", "));
+                }
+            }
+            Fragment::Ident(columns) | Fragment::Raw(columns) => self.group_by_clauses.push(columns),
+        }
+        self
+    }
+
+    /// Adds a HAVING clause to the query.
+    ///
+    /// Used to filter groups created by `group_by`. Similar to `filter` (WHERE),
+    /// but operates on grouped records and aggregate functions.
+    ///
+    /// # Arguments
     ///
-    /// * `col` - The column or aggregate function to filter on
+    /// * `col` - The column or aggregate function to filter on (use `raw()` for aggregates in strict mode)
     /// * `op` - Comparison operator
     /// * `value` - Value to compare against
     ///
@@ -778,24 +1517,34 @@ where
     ///     .scan()
     ///     .await?;
     /// ```
-    pub fn having<V>(mut self, col: &'static str, op: &'static str, value: V) -> Self
+    pub fn having<V>(mut self, col: impl Into<Fragment>, op: &'static str, value: V) -> Self
     where
         V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
     {
-        let clause: FilterFn = Box::new(move |query, args, driver, arg_counter| {
+        let col = match col.into() {
+            Fragment::Ident(col) if self.strict => match self.resolve_column(&col) {
+                Ok(quoted) => quoted,
+                Err(e) => {
+                    self.set_error(e);
+                    return self;
+                }
+            },
+            Fragment::Ident(col) | Fragment::Raw(col) => col,
+        };
+        if self.strict && !STRICT_OPERATORS.contains(&op.to_ascii_uppercase().as_str()) {
+            self.set_error(Error::InvalidArgument(format!("invalid HAVING operator `{}`", op)));
+            return self;
+        }
+
+        let clause: FilterFn = Box::new(move |query, args, dialect, arg_counter| {
             query.push_str(" AND ");
-            query.push_str(col);
+            query.push_str(&col);
             query.push(' ');
             query.push_str(op);
             query.push(' ');
 
-            match driver {
-                Drivers::Postgres => {
-                    query.push_str(&format!("${}", arg_counter));
-                    *arg_counter += 1;
-                }
-                _ => query.push('?'),
-            }
+            query.push_str(&dialect.placeholder(*arg_counter));
+            *arg_counter += 1;
             let _ = args.add(value.clone());
         });
 
@@ -806,21 +1555,21 @@ where
     /// Returns the COUNT of rows matching the query.
     ///
     /// A convenience method that automatically sets `SELECT COUNT(*)` and returns
-    /// the result as an `i64`.
+    /// the result as an `i64`. On a query combined with `union()` and friends it
+    /// counts the rows of the combined result.
     ///
     /// # Returns
     ///
     /// * `Ok(i64)` - The count of rows
-    /// * `Err(sqlx::Error)` - Database error
+    /// * `Err(Error)` - Database error
     ///
     /// # Example
     ///
     /// ```rust,ignore
     /// let user_count = db.model::<User>().count().await?;
     /// ```
-    pub async fn count(mut self) -> Result<i64, sqlx::Error> {
-        self.select_columns = vec!["COUNT(*)".to_string()];
-        self.scalar::<i64>().await
+    pub async fn count(self) -> Result<i64, Error> {
+        self.aggregate::<i64>("COUNT", "*").await
     }
 
     /// Returns the SUM of the specified column.
@@ -836,12 +1585,11 @@ where
     /// ```rust,ignore
     /// let total_age: i64 = db.model::<User>().sum("age").await?;
     /// ```
-    pub async fn sum<N>(mut self, column: &str) -> Result<N, sqlx::Error>
+    pub async fn sum<N>(self, column: &str) -> Result<N, Error>
     where
         N: for<'r> Decode<'r, Any> + Type<Any> + Send + Unpin,
     {
-        self.select_columns = vec![format!("SUM({})", column)];
-        self.scalar::<N>().await
+        self.aggregate::<N>("SUM", column).await
     }
 
     /// Returns the AVG of the specified column.
@@ -857,12 +1605,11 @@ where
     /// ```rust,ignore
     /// let avg_age: f64 = db.model::<User>().avg("age").await?;
     /// ```
-    pub async fn avg<N>(mut self, column: &str) -> Result<N, sqlx::Error>
+    pub async fn avg<N>(self, column: &str) -> Result<N, Error>
     where
         N: for<'r> Decode<'r, Any> + Type<Any> + Send + Unpin,
     {
-        self.select_columns = vec![format!("AVG({})", column)];
-        self.scalar::<N>().await
+        self.aggregate::<N>("AVG", column).await
     }
 
     /// Returns the MIN of the specified column.
@@ -878,12 +1625,11 @@ where
     /// ```rust,ignore
     /// let min_age: i32 = db.model::<User>().min("age").await?;
     /// ```
-    pub async fn min<N>(mut self, column: &str) -> Result<N, sqlx::Error>
+    pub async fn min<N>(self, column: &str) -> Result<N, Error>
     where
         N: for<'r> Decode<'r, Any> + Type<Any> + Send + Unpin,
     {
-        self.select_columns = vec![format!("MIN({})", column)];
-        self.scalar::<N>().await
+        self.aggregate::<N>("MIN", column).await
     }
 
     /// Returns the MAX of the specified column.
@@ -899,12 +1645,54 @@ where
     /// ```rust,ignore
     /// let max_age: i32 = db.model::<User>().max("age").await?;
     /// ```
-    pub async fn max<N>(mut self, column: &str) -> Result<N, sqlx::Error>
+    pub async fn max<N>(self, column: &str) -> Result<N, Error>
+    where
+        N: for<'r> Decode<'r, Any> + Type<Any> + Send + Unpin,
+    {
+        self.aggregate::<N>("MAX", column).await
+    }
+
+    /// Runs the aggregate `function` (e.g. `SUM`) over `column`, or over `*`.
+    ///
+    /// A combined query is wrapped as `SELECT f(col) FROM (...) AS "combined"`,
+    /// since replacing the projection would only change its first SELECT.
+    async fn aggregate<N>(mut self, function: &str, column: &str) -> Result<N, Error>
     where
         N: for<'r> Decode<'r, Any> + Type<Any> + Send + Unpin,
     {
-        self.select_columns = vec![format!("MAX({})", column)];
-        self.scalar::<N>().await
+        if self.compounds.is_empty() {
+            let column = if column == "*" { column.to_string() } else { self.aggregate_column(column) };
+            self.select_columns = vec![format!("{}({})", function, column)];
+            return self.scalar::<N>().await;
+        }
+
+        // The combined rows expose bare column names
+        let column = if column == "*" {
+            column.to_string()
+        } else {
+            self.aggregate_column(column);
+            match column.rsplit('.').next() {
+                Some(name) if column.split('.').all(is_identifier) => self.dialect.quote_ident(name),
+                _ => column.to_string(),
+            }
+        };
+
+        self.take_error()?;
+        self.emulate_lock().await?;
+
+        let combined = self.build();
+        let sql = format!(
+            "SELECT {}({}) FROM ({}) AS {}",
+            function,
+            column,
+            combined.sql,
+            self.dialect.quote_ident("combined")
+        );
+        let compiled = CompiledQuery { sql, ..combined };
+        self.log_sql(&compiled);
+
+        let row = self.fetch_optional_compiled(compiled).await?.ok_or(sqlx::Error::RowNotFound)?;
+        Ok(row.try_get::<N, _>(0)?)
     }
 
     /// Applies pagination with validation and limits.
@@ -974,7 +1762,8 @@ where
     ///
     /// # Arguments
     ///
-    /// * `columns` - Comma-separated list of column names to select
+    /// * `columns` - Comma-separated list of column names to select (validated in strict mode;
+    ///   wrap expressions with `raw()`)
     ///
     /// # Example
     ///
@@ -993,8 +1782,68 @@ where
     ///     .select("id, username")
     ///     .select("created_at")
     /// ```
-    pub fn select(mut self, columns: &str) -> Self {
-        self.select_columns.push(columns.to_string().to_snake_case());
+    pub fn select(mut self, columns: impl Into<Fragment>) -> Self {
+        match columns.into() {
+            Fragment::Ident(columns) if self.strict => {
+                for col in columns.split(',').map(str::trim) {
+                    match self.resolve_column(col) {
+                        Ok(_) => self.select_columns.push(col.to_string()),
+                        Err(e) => self.set_error(e),
+                    }
+                }
+            }
+            Fragment::Ident(columns) => self.select_columns.push(columns.to_snake_case()),
+            Fragment::Raw(expr) => self.select_columns.push(expr),
+        }
+        self
+    }
+
+    /// Adds an aliased expression, such as a window function, to the projection.
+    ///
+    /// The expression is selected as `expr AS "alias"`. When scanning into a
+    /// struct, the field named `alias` receives the value; other results append
+    /// it after the selected columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `expr` - A [`Window`], a column name or a `raw()` expression
+    /// * `alias` - Name of the result column (must be a plain identifier)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use bottle_orm::{Direction, FrameBound, Window};
+    ///
+    /// #[derive(FromAnyRow)]
+    /// struct Running {
+    ///     id: i32,
+    ///     running_total: i64,
+    /// }
+    ///
+    /// let rows: Vec<Running> = db.model::<Order>()
+    ///     .select("id")
+    ///     .select_expr(
+    ///         Window::sum("amount")
+    ///             .partition_by("user_id")
+    ///             .order_by("created_at", Direction::Asc)
+    ///             .rows_between(FrameBound::UnboundedPreceding, FrameBound::CurrentRow),
+    ///         "running_total",
+    ///     )
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn select_expr(mut self, expr: impl Into<Expression>, alias: &str) -> Self {
+        if !is_identifier(alias) {
+            self.set_error(Error::InvalidArgument(format!("invalid alias `{}`", alias)));
+            return self;
+        }
+
+        let rendered = match expr.into() {
+            Expression::Window(window) => window.render(&mut |col| self.expr_column(col)),
+            Expression::Fragment(Fragment::Ident(col)) => self.expr_column(&col),
+            Expression::Fragment(Fragment::Raw(sql)) => sql,
+        };
+        self.select_exprs.push((rendered, alias.to_string()));
         self
     }
 
@@ -1093,6 +1942,8 @@ where
     ///
     /// This method serializes the model into a SQL INSERT statement with proper
     /// type handling for primitives, dates, UUIDs, and other supported types.
+    /// Generated columns (`#[orm(generated = "...")]`) and auto-increment columns
+    /// (`#[orm(auto_increment)]`) are left to the database.
     ///
     /// # Type Binding Strategy
     ///
@@ -1119,7 +1970,7 @@ where
     /// # Returns
     ///
     /// * `Ok(&Self)` - Reference to self for method chaining
-    /// * `Err(sqlx::Error)` - Database error during insertion
+    /// * `Err(Error)` - Database error during insertion
     ///
     /// # Example
     ///
@@ -1138,7 +1989,7 @@ where
     ///
     /// db.model::<User>().insert(&new_user).await?;
     /// ```
-    pub fn insert<'b>(&'b mut self, model: &'b T) -> BoxFuture<'b, Result<(), sqlx::Error>> {
+    pub fn insert<'b>(&'b mut self, model: &'b T) -> BoxFuture<'b, Result<(), Error>> {
         Box::pin(async move {
             // Serialize model to a HashMap of column_name -> string_value
             let data_map = model.to_map();
@@ -1156,9 +2007,14 @@ where
 
             // Build column list and collect values with their SQL types
             for (col_name, value) in data_map {
+                // Generated and auto-increment columns are assigned by the database
+                if columns_info.iter().any(|c| c.name == col_name && (c.generated.is_some() || c.auto_increment)) {
+                    continue;
+                }
+
                 // Strip the "r#" prefix if present (for Rust keywords used as field names)
                 let col_name_clean = col_name.strip_prefix("r#").unwrap_or(&col_name).to_snake_case();
-                target_columns.push(format!("\"{}\"", col_name_clean));
+                target_columns.push(self.dialect.quote_ident(&col_name_clean));
 
                 // Find the SQL type for this column
                 let sql_type = columns_info.iter().find(|c| c.name == col_name).map(|c| c.sql_type).unwrap_or("TEXT");
@@ -1170,30 +2026,14 @@ where
             let placeholders: Vec<String> = bindings
                 .iter()
                 .enumerate()
-                .map(|(i, (_, sql_type))| match self.driver {
-                    Drivers::Postgres => {
-                        let idx = i + 1;
-                        // PostgreSQL requires explicit type casting for some types
-                        if temporal::is_temporal_type(sql_type) {
-                            // Use temporal module for type casting
-                            format!("${}{}", idx, temporal::get_postgres_type_cast(sql_type))
-                        } else {
-                            match *sql_type {
-                                "UUID" => format!("${}::UUID", idx),
-                                "JSONB" | "jsonb" => format!("${}::JSONB", idx),
-                                _ => format!("${}", idx),
-                            }
-                        }
-                    }
-                    // MySQL and SQLite use simple ? placeholders
-                    _ => "?".to_string(),
-                })
+                // The dialect adds explicit casts where the engine requires them (PostgreSQL)
+                .map(|(i, (_, sql_type))| self.dialect.cast_placeholder(self.dialect.placeholder(i + 1), sql_type))
                 .collect();
 
             // Construct the INSERT query
             let query_str = format!(
-                "INSERT INTO \"{}\" ({}) VALUES ({})",
-                table_name,
+                "INSERT INTO {} ({}) VALUES ({})",
+                self.dialect.quote_ident(&table_name),
                 target_columns.join(", "),
                 placeholders.join(", ")
             );
@@ -1203,98 +2043,16 @@ where
                 log::debug!("SQL: {}", query_str);
             }
 
-            let mut query = sqlx::query::<sqlx::Any>(&query_str);
-
-            // Bind values using the optimized value_binding module
-            // This provides type-safe binding with driver-specific optimizations
+            // Bind values through the dialect, falling back to text if a value doesn't parse
+            let mut args = AnyArguments::default();
             for (val_str, sql_type) in bindings {
-                // Create temporary AnyArguments to collect the bound value
-                let mut temp_args = AnyArguments::default();
-
-                // Use the ValueBinder trait for type-safe binding
-                if temp_args.bind_value(&val_str, sql_type, &self.driver).is_ok() {
-                    // For now, we need to convert back to individual bindings
-                    // This is a workaround until we can better integrate AnyArguments
-                    match sql_type {
-                        "INTEGER" | "INT" | "SERIAL" | "serial" | "int4" => {
-                            if let Ok(val) = val_str.parse::<i32>() {
-                                query = query.bind(val);
-                            } else {
-                                query = query.bind(val_str);
-                            }
-                        }
-                        "BIGINT" | "INT8" | "int8" | "BIGSERIAL" => {
-                            if let Ok(val) = val_str.parse::<i64>() {
-                                query = query.bind(val);
-                            } else {
-                                query = query.bind(val_str);
-                            }
-                        }
-                        "BOOLEAN" | "BOOL" | "bool" => {
-                            if let Ok(val) = val_str.parse::<bool>() {
-                                query = query.bind(val);
-                            } else {
-                                query = query.bind(val_str);
-                            }
-                        }
-                        "DOUBLE PRECISION" | "FLOAT" | "float8" | "REAL" | "NUMERIC" | "DECIMAL" => {
-                            if let Ok(val) = val_str.parse::<f64>() {
-                                query = query.bind(val);
-                            } else {
-                                query = query.bind(val_str);
-                            }
-                        }
-                        "UUID" => {
-                            if let Ok(val) = val_str.parse::<Uuid>() {
-                                query = query.bind(val.hyphenated().to_string());
-                            } else {
-                                query = query.bind(val_str);
-                            }
-                        }
-                        "TIMESTAMPTZ" | "DateTime" => {
-                            if let Ok(val) = temporal::parse_datetime_utc(&val_str) {
-                                let formatted = temporal::format_datetime_for_driver(&val, &self.driver);
-                                query = query.bind(formatted);
-                            } else {
-                                query = query.bind(val_str);
-                            }
-                        }
-                        "TIMESTAMP" | "NaiveDateTime" => {
-                            if let Ok(val) = temporal::parse_naive_datetime(&val_str) {
-                                let formatted = temporal::format_naive_datetime_for_driver(&val, &self.driver);
-                                query = query.bind(formatted);
-                            } else {
-                                query = query.bind(val_str);
-                            }
-                        }
-                        "DATE" | "NaiveDate" => {
-                            if let Ok(val) = temporal::parse_naive_date(&val_str) {
-                                let formatted = val.format("%Y-%m-%d").to_string();
-                                query = query.bind(formatted);
-                            } else {
-                                query = query.bind(val_str);
-                            }
-                        }
-                        "TIME" | "NaiveTime" => {
-                            if let Ok(val) = temporal::parse_naive_time(&val_str) {
-                                let formatted = val.format("%H:%M:%S%.6f").to_string();
-                                query = query.bind(formatted);
-                            } else {
-                                query = query.bind(val_str);
-                            }
-                        }
-                        _ => {
-                            query = query.bind(val_str);
-                        }
-                    }
-                } else {
-                    // Fallback: bind as string if type conversion fails
-                    query = query.bind(val_str);
+                if self.dialect.bind_value(&mut args, &val_str, sql_type).is_err() {
+                    let _ = args.add(val_str);
                 }
             }
 
             // Execute the INSERT query
-            query.execute(self.tx.executor()).await?;
+            sqlx::query_with(&query_str, args).execute(self.tx.executor()).await?;
             Ok(())
         })
     }
@@ -1303,11 +2061,65 @@ where
     // Query Execution Methods
     // ========================================================================
 
+    /// Compiles the query into its SQL string and bound arguments without executing it.
+    ///
+    /// The statement is a `SELECT` of the selected columns (or `*`) with every
+    /// accumulated filter, join, grouping, ordering and LIMIT/OFFSET applied. The
+    /// default soft-delete filter is included unless `with_deleted()` was called.
+    ///
+    /// This is the `T`-projection form used by `to_sql()`. `scan::<R>()` selects
+    /// the columns of `R` instead (with `to_json(...)` casts for temporal types);
+    /// use [`build_for()`](Self::build_for) to get that exact statement.
+    ///
+    /// # Returns
+    ///
+    /// A [`CompiledQuery`] holding the SQL and its arguments
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let compiled = db.model::<User>()
+    ///     .filter("age", Op::Gte, 18)
+    ///     .order("created_at DESC")
+    ///     .limit(10)
+    ///     .build();
+    ///
+    /// // SELECT * FROM "user" WHERE 1=1 AND "age" >= $1 ORDER BY created_at DESC LIMIT $2
+    /// println!("SQL: {} ({} args)", compiled.sql, compiled.len());
+    /// ```
+    pub fn build(&self) -> CompiledQuery {
+        self.compile_select(&self.projection(), &[], self.limit, self.offset)
+    }
+
+    /// Compiles the query exactly as `scan::<R>()` would execute it.
+    ///
+    /// The projection is derived from `R` the same way `scan()` derives it, so
+    /// the SQL matches what is sent to the database for that result type.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `R` - The result type the query would be scanned into
+    ///
+    /// # Returns
+    ///
+    /// A [`CompiledQuery`] holding the SQL and its arguments
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let compiled = db.model::<User>().filter("age", Op::Gte, 18).build_for::<UserSummary>();
+    /// let summaries: Vec<UserSummary> = db.model::<User>().filter("age", Op::Gte, 18).scan().await?;
+    /// ```
+    pub fn build_for<R: AnyImpl>(&self) -> CompiledQuery {
+        // Combined queries match columns by position, so keep the order they were selected in
+        let columns = if self.compounds.is_empty() { self.select_args_sql::<R>() } else { self.projection() };
+        self.compile_select(&columns, &[], self.limit, self.offset)
+    }
+
     /// Returns the generated SQL string for debugging purposes.
     ///
-    /// This method constructs the SQL query string without executing it.
-    /// Useful for debugging and logging query construction. Note that this
-    /// shows placeholders (?, $1, etc.) rather than actual bound values.
+    /// This is the SQL part of [`build()`](Self::build). Note that this shows
+    /// placeholders (?, $1, etc.) rather than actual bound values.
     ///
     /// # Returns
     ///
@@ -1322,39 +2134,105 @@ where
     ///     .limit(10);
     ///
     /// println!("SQL: {}", query.to_sql());
-    /// // Output: SELECT * FROM "user" WHERE 1=1 AND "age" >= $1 ORDER BY created_at DESC
+    /// // Output: SELECT * FROM "user" WHERE 1=1 AND "age" >= $1 ORDER BY created_at DESC LIMIT $2
     /// ```
     pub fn to_sql(&self) -> String {
-        let mut query = String::from("SELECT ");
+        self.build().sql
+    }
+
+    /// Returns the generated SQL together with a printable list of the bound values.
+    ///
+    /// Each value is rendered as an SQL literal of the active dialect (`'text'`,
+    /// `42`, `NULL`, ...). Values bound for `#[orm(sensitive)]` columns are shown
+    /// as `'[REDACTED]'`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the SQL (with placeholders) and the values in binding order
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let (sql, params) = db.model::<User>()
+    ///     .filter("username", Op::Eq, "alice".to_string())
+    ///     .limit(1)
+    ///     .to_sql_with_params();
+    ///
+    /// // SELECT * FROM "user" WHERE 1=1 AND "username" = $1 LIMIT $2
+    /// // ["'alice'", "1"]
+    /// println!("{} {:?}", sql, params);
+    /// ```
+    pub fn to_sql_with_params(&self) -> (String, Vec<String>) {
+        let compiled = self.build();
+        let params = self.render_params(&compiled);
+        (compiled.sql, params)
+    }
+
+    /// Returns the generated SQL with every bound value inlined as an escaped literal.
+    ///
+    /// The output can be pasted into `psql`, `mysql` or `sqlite3` to reproduce the
+    /// query. Values of `#[orm(sensitive)]` columns are replaced with `'[REDACTED]'`.
+    /// This is meant for debugging only; queries are always executed with bound
+    /// parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let sql = db.model::<User>().filter("username", Op::Eq, "o'brien".to_string()).interpolated_sql();
+    /// // SELECT * FROM "user" WHERE 1=1 AND "username" = 'o''brien'
+    /// ```
+    pub fn interpolated_sql(&self) -> String {
+        let compiled = self.build();
+        self.dialect.interpolate(&compiled.sql, &self.render_params(&compiled))
+    }
+
+    // ========================================================================
+    // SQL Compilation
+    // ========================================================================
+
+    /// Compiles a SELECT statement over the builder state.
+    ///
+    /// This is the single SELECT compiler shared by `build`, `scan`, `first`,
+    /// `scalar` and pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - Rendered select list entries
+    /// * `fallback_order` - ORDER BY entries used when no `order()` was given
+    /// * `limit` - Row limit, bound as a parameter
+    /// * `offset` - Rows to skip, bound as a parameter
+    pub(crate) fn compile_select(
+        &self,
+        columns: &[String],
+        fallback_order: &[String],
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> CompiledQuery {
+        let mut args = AnyArguments::default();
+        let mut redacted = Vec::new();
+
+        // Common table expressions come first, so their values are bound first
+        let mut query = self.compile_ctes(&mut args, &mut redacted);
+        query.push_str("SELECT ");
 
         if self.is_distinct {
             query.push_str("DISTINCT ");
         }
 
-        // Handle column selection
-        if self.select_columns.is_empty() {
-            query.push('*');
-        } else {
-            query.push_str(&self.select_columns.join(", "));
-        }
-
-        query.push_str(" FROM \"");
-        query.push_str(&self.table_name.to_snake_case());
-        query.push_str("\" ");
+        query.push_str(&columns.join(", "));
 
+        // Build FROM clause
+        query.push_str(" FROM ");
+        query.push_str(&self.dialect.quote_ident(&self.table_name.to_snake_case()));
         if !self.joins_clauses.is_empty() {
+            query.push(' ');
             query.push_str(&self.joins_clauses.join(" "));
         }
 
-        query.push_str(" WHERE 1=1");
-
-        // Apply WHERE clauses with dummy arguments
-        let mut dummy_args = AnyArguments::default();
-        let mut dummy_counter = 1;
+        let mut arg_counter = args.len() + 1;
 
-        for clause in &self.where_clauses {
-            clause(&mut query, &mut dummy_args, &self.driver, &mut dummy_counter);
-        }
+        // Apply WHERE clauses (including the default soft delete filter)
+        redacted.extend(self.compile_where(&mut query, &mut args, &mut arg_counter, !self.with_deleted));
 
         // Apply GROUP BY
         if !self.group_by_clauses.is_empty() {
@@ -1365,28 +2243,322 @@ where
         if !self.having_clauses.is_empty() {
             query.push_str(" HAVING 1=1");
             for clause in &self.having_clauses {
-                clause(&mut query, &mut dummy_args, &self.driver, &mut dummy_counter);
+                clause(&mut query, &mut args, self.dialect.as_ref(), &mut arg_counter);
             }
         }
 
-        // Apply ORDER BY if present
+        // Apply UNION / INTERSECT / EXCEPT; the ORDER BY and LIMIT below apply to the combined result
+        for compound in &self.compounds {
+            let offset = args.len();
+            redacted.extend(compound.redacted.iter().map(|i| i + offset));
+            args.values.0.extend(compound.values.iter().cloned());
+            query.push(' ');
+            query.push_str(compound.operator.as_sql());
+            query.push(' ');
+            query.push_str(&self.renumber_placeholders(&compound.body, compound.values.len(), offset));
+            arg_counter = args.len() + 1;
+        }
+
+        // Apply ORDER BY clauses
+        // We join multiple clauses with commas to form a valid SQL ORDER BY statement
         if !self.order_clauses.is_empty() {
-            query.push_str(&format!(" ORDER BY {}", &self.order_clauses.join(", ")));
+            let order: Vec<String> = self
+                .order_clauses
+                .iter()
+                .map(|clause| match clause {
+                    OrderClause::Raw(expr) => expr.clone(),
+                    OrderClause::Column { column, direction, nulls } => {
+                        self.dialect.order_by(column, *direction, *nulls)
+                    }
+                })
+                .collect();
+            query.push_str(&format!(" ORDER BY {}", order.join(", ")));
+        } else if !fallback_order.is_empty() {
+            query.push_str(&format!(" ORDER BY {}", fallback_order.join(", ")));
+        }
+
+        // Apply LIMIT / OFFSET clauses (rendered by the dialect)
+        let limit_placeholder = limit.map(|limit| {
+            let _ = args.add(limit as i64);
+            arg_counter += 1;
+            self.dialect.placeholder(arg_counter - 1)
+        });
+        let offset_placeholder = offset.map(|offset| {
+            let _ = args.add(offset as i64);
+            arg_counter += 1;
+            self.dialect.placeholder(arg_counter - 1)
+        });
+        query.push_str(&self.dialect.limit_offset(limit_placeholder.as_deref(), offset_placeholder.as_deref()));
+
+        // Apply the row lock (rendered by the dialect)
+        if let Some(mode) = self.lock {
+            query.push_str(&self.dialect.lock_clause(mode, self.lock_wait));
         }
 
-        query
+        CompiledQuery { sql: query, args, redacted }
     }
 
-    /// Generates the list of column selection SQL arguments.
+    /// Appends the WHERE clause to a statement being compiled.
     ///
-    /// This helper function constructs the column list for the SELECT statement.
-    /// It handles:
-    /// 1. Mapping specific columns if `select_columns` is set.
-    /// 2. Defaulting to all columns from the struct `R` if no columns are specified.
-    /// 3. applying `to_json(...)` casting for temporal types when using `AnyImpl` structs,
-    ///    ensuring compatibility with the `FromAnyRow` deserialization logic.
+    /// Writes ` WHERE 1=1`, the soft delete filter (when `soft_delete` is set and
+    /// the model has a `#[orm(soft_delete)]` column) and every registered filter.
+    /// The soft delete column is qualified with the table name when joins are present.
+    ///
+    /// # Returns
+    ///
+    /// The positions in `args` of values bound by filters on sensitive columns
+    pub(crate) fn compile_where(
+        &self,
+        query: &mut String,
+        args: &mut AnyArguments<'static>,
+        arg_counter: &mut usize,
+        soft_delete: bool,
+    ) -> Vec<usize> {
+        query.push_str(" WHERE 1=1");
+
+        if soft_delete && let Some(col) = self.columns_info.iter().find(|c| c.soft_delete).map(|c| c.name) {
+            query.push_str(" AND ");
+            if !self.joins_clauses.is_empty() {
+                query.push_str(&self.dialect.quote_ident(&self.table_name.to_snake_case()));
+                query.push('.');
+            }
+            query.push_str(&self.dialect.quote_ident(col));
+            query.push_str(" IS NULL");
+        }
+
+        let mut redacted = Vec::new();
+        for (i, clause) in self.where_clauses.iter().enumerate() {
+            let bound_before = args.len();
+            clause(query, args, self.dialect.as_ref(), arg_counter);
+            if self.sensitive_filters.contains(&i) {
+                redacted.extend(bound_before..args.len());
+            }
+        }
+        redacted
+    }
+
+    /// Renders the `WITH` clause, appending the values bound by each CTE to `args`.
+    fn compile_ctes(&self, args: &mut AnyArguments<'static>, redacted: &mut Vec<usize>) -> String {
+        if self.ctes.is_empty() {
+            return String::new();
+        }
+
+        let mut parts = Vec::with_capacity(self.ctes.len());
+        for cte in &self.ctes {
+            let offset = args.len();
+            redacted.extend(cte.redacted.iter().map(|i| i + offset));
+            args.values.0.extend(cte.values.iter().cloned());
+            parts.push(format!(
+                "{} AS ({})",
+                self.dialect.quote_ident(&cte.name),
+                self.renumber_placeholders(&cte.body, cte.values.len(), offset)
+            ));
+        }
+
+        let recursive = if self.ctes.iter().any(|c| c.recursive) { "RECURSIVE " } else { "" };
+        format!("WITH {}{} ", recursive, parts.join(", "))
+    }
+
+    /// Shifts the `count` placeholders of a separately compiled statement by `offset` positions.
+    ///
+    /// Positional placeholders (`?`) are unaffected; numbered ones (`$1`) are rewritten
+    /// through the dialect so they follow the values already bound.
+    fn renumber_placeholders(&self, sql: &str, count: usize, offset: usize) -> String {
+        if offset == 0 || count == 0 {
+            return sql.to_string();
+        }
+        let placeholders: Vec<String> = (1..=count).map(|i| self.dialect.placeholder(i + offset)).collect();
+        self.dialect.interpolate(sql, &placeholders)
+    }
+
+    /// Returns `true` if `col` (optionally qualified as `table.column`) is an `#[orm(sensitive)]` column.
+    fn is_sensitive(&self, col: &str) -> bool {
+        let col = col.rsplit('.').next().unwrap_or(col).to_snake_case();
+        self.columns_info
+            .iter()
+            .any(|c| c.sensitive && c.name.strip_prefix("r#").unwrap_or(c.name).to_snake_case() == col)
+    }
+
+    /// Validates a column reference and returns it quoted.
+    ///
+    /// A bare or self-qualified name must be one of the model's columns; a name
+    /// qualified with a table joined via `join_model()` must be one of that
+    /// model's columns, and one qualified with any other joined table must be a
+    /// plain identifier.
+    pub(crate) fn resolve_column(&self, col: &str) -> Result<String, Error> {
+        let (table, name) = match col.split_once('.') {
+            Some((table, name)) => (Some(table), name),
+            None => (None, col),
+        };
+        let own_table = self.table_name.to_snake_case();
+
+        let known = match table {
+            None => self.has_column(name),
+            Some(t) if t == own_table => self.has_column(name),
+            Some(t) => match self.joined_columns.iter().find(|(table, _)| table == t) {
+                Some((_, columns)) => columns.iter().any(|c| c == name),
+                None => self.joined_tables.iter().any(|j| j == t) && is_identifier(name),
+            },
+        };
+
+        if !known {
+            return Err(Error::InvalidArgument(format!("unknown column `{}` for table `{}`", col, own_table)));
+        }
+        Ok(self.dialect.quote_column(col))
+    }
+
+    /// Validates a comma-separated column list, recording an error on the first unknown column.
+    fn resolve_columns(&mut self, columns: &str) -> Option<Vec<String>> {
+        let resolved: Result<Vec<String>, Error> = columns.split(',').map(|c| self.resolve_column(c.trim())).collect();
+        match resolved {
+            Ok(quoted) => Some(quoted),
+            Err(e) => {
+                self.set_error(e);
+                None
+            }
+        }
+    }
+
+    /// Returns the argument of an aggregate helper: validated and quoted in strict mode, verbatim otherwise.
+    fn aggregate_column(&mut self, column: &str) -> String {
+        if !self.strict {
+            return column.to_string();
+        }
+        match self.resolve_column(column) {
+            Ok(quoted) => quoted,
+            Err(e) => {
+                self.set_error(e);
+                column.to_string()
+            }
+        }
+    }
+
+    /// Returns a column referenced inside an expression: validated in strict mode, always quoted.
+    fn expr_column(&mut self, column: &str) -> String {
+        if !self.strict {
+            return self.dialect.quote_column(column);
+        }
+        match self.resolve_column(column) {
+            Ok(quoted) => quoted,
+            Err(e) => {
+                self.set_error(e);
+                self.dialect.quote_column(column)
+            }
+        }
+    }
+
+    /// Returns the selected columns (or `*`) followed by the `select_expr()` expressions, in call order.
+    fn projection(&self) -> Vec<String> {
+        let mut columns =
+            if self.select_columns.is_empty() { vec!["*".to_string()] } else { self.select_columns.clone() };
+        columns.extend(self.select_expr_sql());
+        columns
+    }
+
+    /// Returns the projection entries for expressions added with `select_expr()`.
+    fn select_expr_sql(&self) -> Vec<String> {
+        self.select_exprs
+            .iter()
+            .map(|(expr, alias)| format!("{} AS {}", expr, self.dialect.quote_ident(alias)))
+            .collect()
+    }
+
+    /// Returns the aliased projection entry of the `select_expr()` named `alias`, if any.
+    fn select_expr_for(&self, alias: &str) -> Option<String> {
+        self.select_exprs
+            .iter()
+            .find(|(_, a)| a == alias)
+            .map(|(expr, a)| format!("{} AS {}", expr, self.dialect.quote_ident(a)))
+    }
+
+    /// Returns `true` if `name` is one of the model's columns.
+    fn has_column(&self, name: &str) -> bool {
+        self.columns_info.iter().any(|c| c.name.strip_prefix("r#").unwrap_or(c.name).to_snake_case() == name)
+    }
+
+    /// Records the first invalid builder argument; it is returned when the query executes.
+    pub(crate) fn set_error(&mut self, error: Error) {
+        if self.build_error.is_none() {
+            self.build_error = Some(error);
+        }
+    }
+
+    /// Returns the recorded builder error, if any.
+    pub(crate) fn take_error(&mut self) -> Result<(), Error> {
+        match self.build_error.take() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    /// Renders the bound values of a compiled statement as SQL literals, redacting sensitive ones.
+    fn render_params(&self, compiled: &CompiledQuery) -> Vec<String> {
+        compiled
+            .args
+            .values
+            .0
+            .iter()
+            .enumerate()
+            .map(|(i, value)| if compiled.is_redacted(i) { REDACTED.to_string() } else { self.dialect.literal(value) })
+            .collect()
+    }
+
+    /// Runs the dialect's row lock emulation, if any, before a locking SELECT.
+    async fn emulate_lock(&mut self) -> Result<(), Error> {
+        if self.lock.is_none() {
+            return Ok(());
+        }
+        let table = self.dialect.quote_ident(&self.table_name.to_snake_case());
+        if let Some(sql) = self.dialect.lock_emulation(&table) {
+            self.execute_compiled(CompiledQuery { sql, args: AnyArguments::default(), redacted: Vec::new() }).await?;
+        }
+        Ok(())
+    }
+
+    /// Executes a compiled statement and returns the number of affected rows.
+    ///
+    /// The executor is only borrowed inside boxed futures like this one, which keeps
+    /// the builder's `async fn`s `Send` when awaited inside a closure transaction.
+    fn execute_compiled(&mut self, compiled: CompiledQuery) -> BoxFuture<'_, Result<u64, Error>> {
+        Box::pin(async move {
+            let result = sqlx::query_with(&compiled.sql, compiled.args).execute(self.tx.executor()).await?;
+            Ok(result.rows_affected())
+        })
+    }
+
+    /// Fetches every row returned by a compiled statement.
+    fn fetch_all_compiled(&mut self, compiled: CompiledQuery) -> BoxFuture<'_, Result<Vec<AnyRow>, Error>> {
+        Box::pin(async move { Ok(sqlx::query_with(&compiled.sql, compiled.args).fetch_all(self.tx.executor()).await?) })
+    }
+
+    /// Fetches the first row returned by a compiled statement, if any.
+    pub(crate) fn fetch_optional_compiled(
+        &mut self,
+        compiled: CompiledQuery,
+    ) -> BoxFuture<'_, Result<Option<AnyRow>, Error>> {
+        Box::pin(async move {
+            Ok(sqlx::query_with(&compiled.sql, compiled.args).fetch_optional(self.tx.executor()).await?)
+        })
+    }
+
+    /// Logs the SQL and bound values of a compiled statement when debug mode is active.
+    fn log_sql(&self, compiled: &CompiledQuery) {
+        if self.debug_mode {
+            log::debug!("SQL: {} -- params: [{}]", compiled.sql, self.render_params(compiled).join(", "));
+        }
+    }
+
+    /// Generates the list of column selection SQL arguments.
+    ///
+    /// This helper function constructs the column list for the SELECT statement.
+    /// It handles:
+    /// 1. Mapping specific columns if `select_columns` is set.
+    /// 2. Defaulting to all columns from the struct `R` if no columns are specified.
+    /// 3. applying `to_json(...)` casting for temporal types when using `AnyImpl` structs,
+    ///    ensuring compatibility with the `FromAnyRow` deserialization logic.
     fn select_args_sql<R: AnyImpl>(&self) -> Vec<String> {
         let struct_cols = R::columns();
+        let quote = |ident: &str| self.dialect.quote_ident(ident);
 
         if !struct_cols.is_empty() {
             if !self.select_columns.is_empty() {
@@ -1394,22 +2566,20 @@ where
                 for col_info in struct_cols {
                     let col_snake = col_info.column.to_snake_case();
                     let sql_type = col_info.sql_type;
-                    if self.select_columns.contains(&col_snake) {
-                        if is_temporal_type(sql_type) && matches!(self.driver, Drivers::Postgres) {
-                            if !self.joins_clauses.is_empty() {
-                                args.push(format!(
-                                    "to_json(\"{}\".\"{}\") #>> '{{}}' AS \"{}\"",
-                                    self.table_name.to_snake_case(),
-                                    col_snake,
-                                    col_snake
-                                ));
+                    if let Some(expr) = self.select_expr_for(&col_snake) {
+                        args.push(expr);
+                    } else if self.select_columns.contains(&col_snake) {
+                        if is_temporal_type(sql_type) {
+                            let column = if !self.joins_clauses.is_empty() {
+                                format!("{}.{}", quote(&self.table_name.to_snake_case()), quote(&col_snake))
                             } else {
-                                args.push(format!("to_json(\"{}\") #>> '{{}}' AS \"{}\"", col_snake, col_snake));
-                            }
+                                quote(&col_snake)
+                            };
+                            args.push(self.dialect.select_temporal(&column, &quote(&col_snake)));
                         } else if !self.joins_clauses.is_empty() {
-                            args.push(format!("\"{}\".\"{}\"", self.table_name.to_snake_case(), col_snake));
+                            args.push(format!("{}.{}", quote(&self.table_name.to_snake_case()), quote(&col_snake)));
                         } else {
-                            args.push(format!("\"{}\"", col_snake));
+                            args.push(quote(&col_snake));
                         }
                     }
                 }
@@ -1423,8 +2593,11 @@ where
                         let is_omitted = self.omit_columns.contains(&col_snake);
                         let table_name =
                             if !c.table.is_empty() { c.table.to_snake_case() } else { self.table_name.to_snake_case() };
+                        let alias = quote(&format!("{}__{}", table_name, col_snake));
 
-                        if is_omitted {
+                        if let Some(expr) = self.select_expr_for(&col_snake) {
+                            expr
+                        } else if is_omitted {
                             // Return type-appropriate placeholder based on sql_type
                             let placeholder = match c.sql_type {
                                 // String types
@@ -1445,14 +2618,12 @@ where
                                 // Default fallback for unknown types
                                 _ => "'omited'",
                             };
-                            format!("{} AS \"{}__{}\"", placeholder, table_name, col_snake)
-                        } else if is_temporal_type(c.sql_type) && matches!(self.driver, Drivers::Postgres) {
-                            format!(
-                                "to_json(\"{}\".\"{}\") #>> '{{}}' AS \"{}__{}\"",
-                                table_name, col_snake, table_name, col_snake
-                            )
+                            format!("{} AS {}", placeholder, alias)
+                        } else if is_temporal_type(c.sql_type) {
+                            let column = format!("{}.{}", quote(&table_name), quote(&col_snake));
+                            self.dialect.select_temporal(&column, &alias)
                         } else {
-                            format!("\"{}\".\"{}\" AS \"{}__{}\"", table_name, col_snake, table_name, col_snake)
+                            format!("{}.{} AS {}", quote(&table_name), quote(&col_snake), alias)
                         }
                     })
                     .collect();
@@ -1460,14 +2631,15 @@ where
         }
 
         if !self.select_columns.is_empty() {
-            return self
-                .select_columns
-                .iter()
-                .map(|c| if c.contains('(') { c.clone() } else { format!("\"{}\"", c) })
-                .collect();
+            let mut args: Vec<String> =
+                self.select_columns.iter().map(|c| if c.contains('(') { c.clone() } else { quote(c) }).collect();
+            args.extend(self.select_expr_sql());
+            return args;
         }
 
-        vec!["*".to_string()]
+        let mut args = vec!["*".to_string()];
+        args.extend(self.select_expr_sql());
+        args
     }
 
     /// Executes the query and returns a list of results.
@@ -1482,7 +2654,7 @@ where
     /// # Returns
     ///
     /// * `Ok(Vec<R>)` - Vector of results (empty if no matches)
-    /// * `Err(sqlx::Error)` - Database error during query execution
+    /// * `Err(Error)` - Database error during query execution
     ///
     /// # Example
     ///
@@ -1508,98 +2680,19 @@ where
     ///     .scan()
     ///     .await?;  // Returns empty Vec, not an error
     /// ```
-    pub async fn scan<R>(mut self) -> Result<Vec<R>, sqlx::Error>
+    pub async fn scan<R>(mut self) -> Result<Vec<R>, Error>
     where
         R: FromAnyRow + AnyImpl + Send + Unpin,
     {
-        // Apply default soft delete filter if not disabled
-        if !self.with_deleted {
-            if let Some(soft_delete_col) = self.columns_info.iter().find(|c| c.soft_delete).map(|c| c.name) {
-                self = self.is_null(soft_delete_col);
-            }
-        }
-
-        // Build SELECT clause
-        let mut query = String::from("SELECT ");
-
-        if self.is_distinct {
-            query.push_str("DISTINCT ");
-        }
-
-        query.push_str(&self.select_args_sql::<R>().join(", "));
-
-        // Build FROM clause
-        query.push_str(" FROM \"");
-        query.push_str(&self.table_name.to_snake_case());
-        query.push_str("\" ");
-        if !self.joins_clauses.is_empty() {
-            query.push_str(&self.joins_clauses.join(" "));
-        }
-
-        query.push_str(" WHERE 1=1");
-
-        // Apply WHERE clauses
-        let mut args = AnyArguments::default();
-        let mut arg_counter = 1;
-
-        for clause in &self.where_clauses {
-            clause(&mut query, &mut args, &self.driver, &mut arg_counter);
-        }
-
-        // Apply GROUP BY
-        if !self.group_by_clauses.is_empty() {
-            query.push_str(&format!(" GROUP BY {}", self.group_by_clauses.join(", ")));
-        }
-
-        // Apply HAVING
-        if !self.having_clauses.is_empty() {
-            query.push_str(" HAVING 1=1");
-            for clause in &self.having_clauses {
-                clause(&mut query, &mut args, &self.driver, &mut arg_counter);
-            }
-        }
-
-        // Apply ORDER BY clauses
-        // We join multiple clauses with commas to form a valid SQL ORDER BY statement
-        if !self.order_clauses.is_empty() {
-            query.push_str(&format!(" ORDER BY {}", self.order_clauses.join(", ")));
-        }
-
-        // Apply LIMIT clause
-        if let Some(limit) = self.limit {
-            query.push_str(" LIMIT ");
-            match self.driver {
-                Drivers::Postgres => {
-                    query.push_str(&format!("${}", arg_counter));
-                    arg_counter += 1;
-                }
-                _ => query.push('?'),
-            }
-            let _ = args.add(limit as i64);
-        }
-
-        // Apply OFFSET clause
-        if let Some(offset) = self.offset {
-            query.push_str(" OFFSET ");
-            match self.driver {
-                Drivers::Postgres => {
-                    query.push_str(&format!("${}", arg_counter));
-                    // arg_counter += 1; // Not needed as this is the last clause
-                }
-                _ => query.push('?'),
-            }
-            let _ = args.add(offset as i64);
-        }
-
-        // Print SQL query to logs if debug mode is active
-        if self.debug_mode {
-            log::debug!("SQL: {}", query);
-        }
+        self.take_error()?;
+        self.emulate_lock().await?;
+        let compiled = self.build_for::<R>();
+        self.log_sql(&compiled);
 
         // Execute query and fetch all results
-        let rows = sqlx::query_with(&query, args).fetch_all(self.tx.executor()).await?;
+        let rows = self.fetch_all_compiled(compiled).await?;
 
-        rows.iter().map(|row| R::from_any_row(row)).collect()
+        Ok(rows.iter().map(|row| R::from_any_row(row)).collect::<Result<_, _>>()?)
     }
 
     /// Executes the query and returns only the first result.
@@ -1615,11 +2708,11 @@ where
     /// # Returns
     ///
     /// * `Ok(R)` - The first matching row
-    /// * `Err(sqlx::Error)` - No rows found or database error
+    /// * `Err(Error)` - No rows found or database error
     ///
     /// # Error Handling
     ///
-    /// Returns `sqlx::Error::RowNotFound` if no rows match the query.
+    /// Returns `Error::NotFound` if no rows match the query.
     /// Use `scan()` instead if you want an empty Vec rather than an error.
     ///
     /// # Example
@@ -1647,87 +2740,232 @@ where
     /// // Error handling
     /// match db.model::<User>().filter("id", "=", 999).first().await {
     ///     Ok(user) => println!("Found: {:?}", user),
-    ///     Err(sqlx::Error::RowNotFound) => println!("User not found"),
+    ///     Err(Error::NotFound) => println!("User not found"),
     ///     Err(e) => println!("Database error: {}", e),
     /// }
     /// ```
-    pub async fn first<R>(mut self) -> Result<R, sqlx::Error>
+    pub async fn first<R>(mut self) -> Result<R, Error>
     where
         R: FromAnyRow + AnyImpl + Send + Unpin,
     {
-        // Apply default soft delete filter if not disabled
-        if !self.with_deleted {
-            if let Some(soft_delete_col) = self.columns_info.iter().find(|c| c.soft_delete).map(|c| c.name) {
-                self = self.is_null(soft_delete_col);
-            }
-        }
+        self.take_error()?;
+        self.emulate_lock().await?;
 
-        // Build SELECT clause
-        let mut query = String::from("SELECT ");
+        self.fetch_first().await?.ok_or(Error::NotFound)
+    }
 
-        if self.is_distinct {
-            query.push_str("DISTINCT ");
+    /// Fetches the row `first()` would return, or `None`, without consuming the builder.
+    async fn fetch_first<R>(&mut self) -> Result<Option<R>, Error>
+    where
+        R: FromAnyRow + AnyImpl + Send + Unpin,
+    {
+        // Fallback to PK ordering if no custom order is specified (ensures deterministic results)
+        let pk_order: Vec<String> = T::primary_keys()
+            .iter()
+            .map(|pk| {
+                let pk = pk.strip_prefix("r#").unwrap_or(pk).to_snake_case();
+                format!("{}.{} ASC", self.dialect.quote_ident(&self.table_name.to_snake_case()), self.dialect.quote_ident(&pk))
+            })
+            .collect();
+
+        // Always limit first() queries to a single row
+        let compiled = self.compile_select(&self.select_args_sql::<R>(), &pk_order, Some(1), self.offset);
+        self.log_sql(&compiled);
+
+        let row = self.fetch_optional_compiled(compiled).await?;
+        Ok(row.map(|row| R::from_any_row(&row)).transpose()?)
+    }
+
+    /// Executes the query and returns the first result, or `None` if no rows match.
+    ///
+    /// Works like `first()`, but a missing row is not an error.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(R))` - The first matching row
+    /// * `Ok(None)` - No rows matched
+    /// * `Err(Error)` - Database error
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let admin: Option<User> = db.model::<User>()
+    ///     .filter("role", Op::Eq, "admin")
+    ///     .first_optional()
+    ///     .await?;
+    /// ```
+    pub async fn first_optional<R>(self) -> Result<Option<R>, Error>
+    where
+        R: FromAnyRow + AnyImpl + Send + Unpin,
+    {
+        match self.first().await {
+            Ok(row) => Ok(Some(row)),
+            Err(Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
         }
+    }
 
-        query.push_str(&self.select_args_sql::<R>().join(", "));
+    /// Returns `true` if at least one row matches the query.
+    ///
+    /// Runs `SELECT 1 ... LIMIT 1` rather than counting rows. `SELECT EXISTS(...)`
+    /// is avoided because its result type differs between drivers (boolean on
+    /// PostgreSQL, integer on MySQL and SQLite). Soft-deleted rows are excluded
+    /// unless `with_deleted()` was called.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let taken = db.model::<User>().filter("email", Op::Eq, email).exists().await?;
+    /// ```
+    pub async fn exists(mut self) -> Result<bool, Error> {
+        self.take_error()?;
+        self.emulate_lock().await?;
 
-        // Build FROM clause
-        query.push_str(" FROM \"");
-        query.push_str(&self.table_name.to_snake_case());
-        query.push_str("\" ");
-        if !self.joins_clauses.is_empty() {
-            query.push_str(&self.joins_clauses.join(" "));
-        }
+        // Combined queries need matching projections on both sides
+        let columns = if self.compounds.is_empty() { vec!["1".to_string()] } else { self.projection() };
+        let compiled = self.compile_select(&columns, &[], Some(1), self.offset);
+        self.log_sql(&compiled);
 
-        query.push_str(" WHERE 1=1");
+        let row = self.fetch_optional_compiled(compiled).await?;
+        Ok(row.is_some())
+    }
 
-        // Apply WHERE clauses
-        let mut args = AnyArguments::default();
-        let mut arg_counter = 1;
+    // ========================================================================
+    // Primary Key Lookups
+    // ========================================================================
 
-        for clause in &self.where_clauses {
-            clause(&mut query, &mut args, &self.driver, &mut arg_counter);
-        }
+    /// Finds the model with the given primary key.
+    ///
+    /// The primary key column is taken from the model's `ColumnInfo`; models with
+    /// a composite primary key are rejected with `Error::InvalidArgument`. Other
+    /// filters on the builder still apply, and so does the soft delete filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The primary key value
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(T))` - The model
+    /// * `Ok(None)` - No row has this primary key
+    /// * `Err(Error)` - Database error or composite primary key
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let user: Option<User> = db.model::<User>().find(user_id).await?;
+    /// ```
+    pub async fn find<V>(self, id: V) -> Result<Option<T>, Error>
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+        T: FromAnyRow + AnyImpl + Send + Unpin,
+    {
+        self.where_primary_key("find", vec![id]).first_optional().await
+    }
 
-        // Apply GROUP BY
-        if !self.group_by_clauses.is_empty() {
-            query.push_str(&format!(" GROUP BY {}", self.group_by_clauses.join(", ")));
+    /// Finds the models with the given primary keys.
+    ///
+    /// Generates `WHERE pk IN (...)`. Keys without a matching row are skipped,
+    /// and an empty key list returns an empty `Vec` without querying.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let users: Vec<User> = db.model::<User>().order("id").find_many(vec![1, 2, 3]).await?;
+    /// ```
+    pub async fn find_many<V>(mut self, ids: impl IntoIterator<Item = V>) -> Result<Vec<T>, Error>
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+        T: FromAnyRow + AnyImpl + Send + Unpin,
+    {
+        let ids: Vec<V> = ids.into_iter().collect();
+        if ids.is_empty() {
+            self.take_error()?;
+            return Ok(Vec::new());
         }
+        self.where_primary_key("find_many", ids).scan().await
+    }
 
-        // Apply HAVING
-        if !self.having_clauses.is_empty() {
-            query.push_str(" HAVING 1=1");
-            for clause in &self.having_clauses {
-                clause(&mut query, &mut args, &self.driver, &mut arg_counter);
-            }
-        }
+    /// Finds the model with the given primary key, failing if it does not exist.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` - The model
+    /// * `Err(Error::NotFound)` - No row has this primary key
+    /// * `Err(Error)` - Database error or composite primary key
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let user: User = db.model::<User>().find_or_fail(user_id).await?;
+    /// ```
+    pub async fn find_or_fail<V>(self, id: V) -> Result<T, Error>
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+        T: FromAnyRow + AnyImpl + Send + Unpin,
+    {
+        self.where_primary_key("find_or_fail", vec![id]).first().await
+    }
 
-        // Find primary key column for consistent ordering
-        let pk_column = T::columns()
-            .iter()
-            .find(|c| c.is_primary_key)
-            .map(|c| c.name.strip_prefix("r#").unwrap_or(c.name).to_snake_case());
+    /// Adds `pk = ?` (one value) or `pk IN (...)` (several) on the model's primary key.
+    ///
+    /// Records an error when the model has no primary key or a composite one.
+    pub(crate) fn where_primary_key<V>(mut self, method: &str, ids: Vec<V>) -> Self
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+    {
+        let keys: Vec<&'static str> = self.columns_info.iter().filter(|c| c.is_primary_key).map(|c| c.name).collect();
+        let [key] = keys[..] else {
+            self.set_error(Error::InvalidArgument(format!(
+                "{}() requires a single-column primary key on `{}`",
+                method, self.table_name
+            )));
+            return self;
+        };
+
+        let table = self.table_name.to_snake_case();
+        let column = key.strip_prefix("r#").unwrap_or(key).to_snake_case();
+        let clause: FilterFn = Box::new(move |query, args, dialect, arg_counter| {
+            let placeholders: Vec<String> = ids
+                .iter()
+                .map(|id| {
+                    let _ = args.add(id.clone());
+                    *arg_counter += 1;
+                    dialect.placeholder(*arg_counter - 1)
+                })
+                .collect();
 
-        // Apply ORDER BY clauses
-        // We join multiple clauses with commas to form a valid SQL ORDER BY statement
-        if !self.order_clauses.is_empty() {
-            query.push_str(&format!(" ORDER BY {}", self.order_clauses.join(", ")));
-        } else if let Some(pk) = pk_column {
-            // Fallback to PK ordering if no custom order is specified (ensures deterministic results)
-            query.push_str(" ORDER BY ");
-            query.push_str(&format!("\"{}\".\"{}\"", self.table_name.to_snake_case(), pk));
-            query.push_str(" ASC");
-        }
+            let column = format!("{}.{}", dialect.quote_ident(&table), dialect.quote_ident(&column));
+            match placeholders.as_slice() {
+                [single] => query.push_str(&format!(" AND {} = {}", column, single)),
+                _ => query.push_str(&format!(" AND {} IN ({})", column, placeholders.join(", "))),
+            }
+        });
 
-        // Always add LIMIT 1 for first() queries
-        query.push_str(" LIMIT 1");
+        if self.is_sensitive(key) {
+            self.sensitive_filters.push(self.where_clauses.len());
+        }
+        self.where_clauses.push(clause);
+        self
+    }
 
-        // Print SQL query to logs if debug mode is active
-        log::debug!("SQL: {}", query);
+    /// Adds `column = value` on every primary key column, with the values of `model`.
+    ///
+    /// Records an error when the model has no primary key or a key value is `None`.
+    pub(crate) fn where_key_of(mut self, method: &str, model: &T) -> Self {
+        let keys = T::primary_keys();
+        let mut values = Model::to_map(model);
+        values.retain(|col, _| keys.contains(&col.as_str()));
 
-        // Execute query and fetch exactly one result
-        let row = sqlx::query_with(&query, args).fetch_one(self.tx.executor()).await?;
-        R::from_any_row(&row)
+        if keys.is_empty() || values.len() != keys.len() {
+            self.set_error(Error::InvalidArgument(format!(
+                "{}() requires a primary key value on `{}`",
+                method, self.table_name
+            )));
+            return self;
+        }
+        self.where_values(method, values);
+        self
     }
 
     /// Executes the query and returns a single scalar value.
@@ -1735,6 +2973,10 @@ where
     /// This method is useful for fetching single values like counts, max/min values,
     /// or specific columns without mapping to a struct or tuple.
     ///
+    /// Compound queries built with `union()`, `intersect()` or `except()` are rejected with
+    /// `Error::InvalidArgument`, because an aggregate in the selection would be computed per branch.
+    /// Use `count()`, `sum()`, `avg()`, `min()` or `max()` to aggregate over the combined rows.
+    ///
     /// # Type Parameters
     ///
     /// * `O` - The output type. Must implement `Decode` and `Type`.
@@ -1755,93 +2997,47 @@ where
     ///     .scalar()
     ///     .await?;
     /// ```
-    pub async fn scalar<O>(mut self) -> Result<O, sqlx::Error>
+    pub async fn scalar<O>(mut self) -> Result<O, Error>
     where
         O: for<'r> Decode<'r, Any> + Type<Any> + Send + Unpin,
     {
-        // Apply default soft delete filter if not disabled
-        if !self.with_deleted {
-            if let Some(soft_delete_col) = self.columns_info.iter().find(|c| c.soft_delete).map(|c| c.name) {
-                self = self.is_null(soft_delete_col);
-            }
+        self.take_error()?;
+        if !self.compounds.is_empty() {
+            return Err(Error::InvalidArgument(
+                "scalar() is not supported on set operations; use count(), sum(), avg(), min() or max()".to_string(),
+            ));
         }
+        self.emulate_lock().await?;
 
-        // Build SELECT clause
-        let mut query = String::from("SELECT ");
-
-        if self.is_distinct {
-            query.push_str("DISTINCT ");
-        }
-
-        if self.select_columns.is_empty() {
-            return Err(sqlx::Error::ColumnNotFound("is not possible get data without column".to_string()));
+        if self.select_columns.is_empty() && self.select_exprs.is_empty() {
+            return Err(sqlx::Error::ColumnNotFound("is not possible get data without column".to_string()).into());
         }
 
-        let mut select_cols = Vec::with_capacity(self.select_columns.capacity());
-        for col in self.select_columns {
-            if !self.joins_clauses.is_empty() {
-                if let Some((table, column)) = col.split_once(".") {
-                    select_cols.push(format!("\"{}\".\"{}\"", table, column));
+        let mut select_cols = Vec::with_capacity(self.select_columns.len());
+        for col in &self.select_columns {
+            // Qualify plain column names when joins make them ambiguous; expressions are kept as-is
+            if !self.joins_clauses.is_empty() && col.split('.').all(is_identifier) {
+                if col.contains('.') {
+                    select_cols.push(self.dialect.quote_column(col));
                 } else {
-                    select_cols.push(format!("\"{}\".\"{}\"", self.table_name.to_snake_case(), col));
+                    let table = self.table_name.to_snake_case();
+                    select_cols.push(format!("{}.{}", self.dialect.quote_ident(&table), self.dialect.quote_ident(col)));
                 }
                 continue;
             }
-            select_cols.push(col);
+            select_cols.push(col.clone());
         }
+        select_cols.extend(self.select_expr_sql());
 
-        query.push_str(&select_cols.join(", "));
-
-        // Build FROM clause
-        query.push_str(" FROM \"");
-        query.push_str(&self.table_name.to_snake_case());
-        query.push_str("\" ");
-
-        if !self.joins_clauses.is_empty() {
-            query.push_str(&self.joins_clauses.join(" "));
-        }
-
-        query.push_str(" WHERE 1=1");
-
-        // Apply WHERE clauses
-        let mut args = AnyArguments::default();
-        let mut arg_counter = 1;
-
-        for clause in &self.where_clauses {
-            clause(&mut query, &mut args, &self.driver, &mut arg_counter);
-        }
-
-        // Apply GROUP BY
-        if !self.group_by_clauses.is_empty() {
-            query.push_str(&format!(" GROUP BY {}", self.group_by_clauses.join(", ")));
-        }
-
-        // Apply HAVING
-        if !self.having_clauses.is_empty() {
-            query.push_str(" HAVING 1=1");
-            for clause in &self.having_clauses {
-                clause(&mut query, &mut args, &self.driver, &mut arg_counter);
-            }
-        }
-
-        // Apply ORDER BY
-        if !self.order_clauses.is_empty() {
-            query.push_str(&format!(" ORDER BY {}", &self.order_clauses.join(", ")));
-        }
-
-        // Always add LIMIT 1 for scalar queries
-        query.push_str(" LIMIT 1");
-
-        // Print SQL query to logs if debug mode is active
-        if self.debug_mode {
-            log::debug!("SQL: {}", query);
-        }
+        // Always limit scalar queries to a single row
+        let compiled = self.compile_select(&select_cols, &[], Some(1), self.offset);
+        self.log_sql(&compiled);
 
         // Execute query and fetch one row
-        let row = sqlx::query_with::<_, _>(&query, args).fetch_one(self.tx.executor()).await?;
+        let row = self.fetch_optional_compiled(compiled).await?.ok_or(sqlx::Error::RowNotFound)?;
 
         // Get the first column
-        row.try_get::<O, _>(0)
+        Ok(row.try_get::<O, _>(0)?)
     }
 
     /// Updates a single column in the database.
@@ -1854,7 +3050,7 @@ where
     /// # Returns
     ///
     /// * `Ok(u64)` - The number of rows affected
-    pub fn update<'b, V>(&'b mut self, col: &str, value: V) -> BoxFuture<'b, Result<u64, sqlx::Error>>
+    pub fn update<'b, V>(&'b mut self, col: &str, value: V) -> BoxFuture<'b, Result<u64, Error>>
     where
         V: ToString + Send + Sync,
     {
@@ -1866,6 +3062,7 @@ where
     /// Updates all columns based on the model instance.
     ///
     /// This method updates all active columns of the table with values from the provided model.
+    /// Generated columns (`#[orm(generated = "...")]`) are skipped.
     ///
     /// # Arguments
     ///
@@ -1874,7 +3071,7 @@ where
     /// # Returns
     ///
     /// * `Ok(u64)` - The number of rows affected
-    pub fn updates<'b>(&'b mut self, model: &T) -> BoxFuture<'b, Result<u64, sqlx::Error>> {
+    pub fn updates<'b>(&'b mut self, model: &T) -> BoxFuture<'b, Result<u64, Error>> {
         self.execute_update(model.to_map())
     }
 
@@ -1890,7 +3087,7 @@ where
     /// # Returns
     ///
     /// * `Ok(u64)` - The number of rows affected
-    pub fn update_partial<'b, P: AnyImpl>(&'b mut self, partial: &P) -> BoxFuture<'b, Result<u64, sqlx::Error>> {
+    pub fn update_partial<'b, P: AnyImpl>(&'b mut self, partial: &P) -> BoxFuture<'b, Result<u64, Error>> {
         self.execute_update(partial.to_map())
     }
 
@@ -1898,25 +3095,14 @@ where
     fn execute_update<'b>(
         &'b mut self,
         data_map: std::collections::HashMap<String, String>,
-    ) -> BoxFuture<'b, Result<u64, sqlx::Error>> {
-        // Apply default soft delete filter if not disabled
-        if !self.with_deleted {
-            if let Some(soft_delete_col) = self.columns_info.iter().find(|c| c.soft_delete).map(|c| c.name) {
-                let col_owned = soft_delete_col.to_string();
-                let clause: FilterFn = Box::new(move |query, _args, _driver, _arg_counter| {
-                    query.push_str(" AND ");
-                    query.push_str(&format!("\"{}\"", col_owned));
-                    query.push_str(" IS NULL");
-                });
-                self.where_clauses.push(clause);
-            }
-        }
-
+    ) -> BoxFuture<'b, Result<u64, Error>> {
         Box::pin(async move {
+            self.take_error()?;
+
             let table_name = self.table_name.to_snake_case();
-            let mut query = format!("UPDATE \"{}\" SET ", table_name);
+            let mut query = format!("UPDATE {} SET ", self.dialect.quote_ident(&table_name));
 
-            let mut bindings: Vec<(String, &str)> = Vec::new();
+            let mut bindings: Vec<(String, &str, bool)> = Vec::new();
             let mut set_clauses = Vec::new();
 
             // Maintain argument counter for PostgreSQL ($1, $2, ...)
@@ -1927,35 +3113,23 @@ where
                 // Strip the "r#" prefix if present
                 let col_name_clean = col_name.strip_prefix("r#").unwrap_or(&col_name).to_snake_case();
 
+                // Find the column in the Model metadata
+                let column = self.columns_info.iter().find(|c| c.name == col_name || c.name == col_name_clean);
+
+                // Generated columns are computed by the database and cannot be written
+                if column.is_some_and(|c| c.generated.is_some()) {
+                    continue;
+                }
+
                 // Find the SQL type for this column from the Model metadata
-                let sql_type = self
-                    .columns_info
-                    .iter()
-                    .find(|c| c.name == col_name || c.name == col_name_clean)
-                    .map(|c| c.sql_type)
-                    .unwrap_or("TEXT");
+                let sql_type = column.map(|c| c.sql_type).unwrap_or("TEXT");
 
                 // Generate placeholder
-                let placeholder = match self.driver {
-                    Drivers::Postgres => {
-                        let idx = arg_counter;
-                        arg_counter += 1;
-
-                        if temporal::is_temporal_type(sql_type) {
-                            format!("${}{}", idx, temporal::get_postgres_type_cast(sql_type))
-                        } else {
-                            match sql_type {
-                                "UUID" => format!("${}::UUID", idx),
-                                "JSONB" | "jsonb" => format!("${}::JSONB", idx),
-                                _ => format!("${}", idx),
-                            }
-                        }
-                    }
-                    _ => "?".to_string(),
-                };
+                let placeholder = self.dialect.cast_placeholder(self.dialect.placeholder(arg_counter), sql_type);
+                arg_counter += 1;
 
-                set_clauses.push(format!("\"{}\" = {}", col_name_clean, placeholder));
-                bindings.push((value, sql_type));
+                set_clauses.push(format!("{} = {}", self.dialect.quote_ident(&col_name_clean), placeholder));
+                bindings.push((value, sql_type, column.is_some_and(|c| c.sensitive)));
             }
 
             // If no fields to update, return 0
@@ -1965,30 +3139,26 @@ where
 
             query.push_str(&set_clauses.join(", "));
 
-            // Build WHERE clause
-            query.push_str(" WHERE 1=1");
-
             let mut args = AnyArguments::default();
+            let mut redacted = Vec::new();
 
             // Bind SET values
-            for (val_str, sql_type) in bindings {
-                if args.bind_value(&val_str, sql_type, &self.driver).is_err() {
+            for (val_str, sql_type, sensitive) in bindings {
+                if sensitive {
+                    redacted.push(args.len());
+                }
+                if self.dialect.bind_value(&mut args, &val_str, sql_type).is_err() {
                     let _ = args.add(val_str);
                 }
             }
 
             // Apply WHERE clauses (appending to args and query)
-            for clause in &self.where_clauses {
-                clause(&mut query, &mut args, &self.driver, &mut arg_counter);
-            }
-
-            // Print SQL query to logs if debug mode is active
-            if self.debug_mode {
-                log::debug!("SQL: {}", query);
-            }
+            redacted.extend(self.compile_where(&mut query, &mut args, &mut arg_counter, !self.with_deleted));
+            let compiled = CompiledQuery { sql: query, args, redacted };
+            self.log_sql(&compiled);
 
             // Execute the UPDATE query
-            let result = sqlx::query_with(&query, args).execute(self.tx.executor()).await?;
+            let result = sqlx::query_with(&compiled.sql, compiled.args).execute(self.tx.executor()).await?;
 
             Ok(result.rows_affected())
         })
@@ -1998,67 +3168,40 @@ where
     ///
     /// If the model has a `#[orm(soft_delete)]` column, this method performs
     /// an UPDATE setting the soft delete column to the current timestamp instead
-    /// of physically deleting the record.
+    /// of physically deleting the record. Rows that are already soft-deleted are
+    /// left untouched unless `with_deleted()` was called.
     ///
     /// For permanent deletion, use `hard_delete()`.
     ///
     /// # Returns
     ///
     /// * `Ok(u64)` - The number of rows deleted (or soft-deleted)
-    /// * `Err(sqlx::Error)` - Database error
-    pub async fn delete(mut self) -> Result<u64, sqlx::Error> {
-        // Check for soft delete column
-        let soft_delete_col = self.columns_info.iter().find(|c| c.soft_delete).map(|c| c.name);
-
-        if let Some(col) = soft_delete_col {
-            // Soft Delete: Update the column to current timestamp
-            let table_name = self.table_name.to_snake_case();
-            let mut query = format!("UPDATE \"{}\" SET \"{}\" = ", table_name, col);
+    /// * `Err(Error)` - Database error
+    pub async fn delete(mut self) -> Result<u64, Error> {
+        self.take_error()?;
 
-            match self.driver {
-                Drivers::Postgres => query.push_str("NOW()"),
-                Drivers::SQLite => query.push_str("strftime('%Y-%m-%dT%H:%M:%SZ', 'now')"),
-                Drivers::MySQL => query.push_str("NOW()"),
-            }
-
-            query.push_str(" WHERE 1=1");
-
-            let mut args = AnyArguments::default();
-            let mut arg_counter = 1;
-
-            // Apply filters
-            for clause in &self.where_clauses {
-                clause(&mut query, &mut args, &self.driver, &mut arg_counter);
-            }
-
-            // Print SQL query to logs if debug mode is active
-            if self.debug_mode {
-                log::debug!("SQL: {}", query);
-            }
-
-            let result = sqlx::query_with(&query, args).execute(self.tx.executor()).await?;
-            Ok(result.rows_affected())
-        } else {
+        // Check for soft delete column
+        let Some(col) = self.columns_info.iter().find(|c| c.soft_delete).map(|c| c.name) else {
             // Standard Delete (no soft delete column)
-            let mut query = String::from("DELETE FROM \"");
-            query.push_str(&self.table_name.to_snake_case());
-            query.push_str("\" WHERE 1=1");
+            return self.hard_delete().await;
+        };
 
-            let mut args = AnyArguments::default();
-            let mut arg_counter = 1;
+        // Soft Delete: Update the column to current timestamp (skipping rows already deleted)
+        let mut query = format!(
+            "UPDATE {} SET {} = {}",
+            self.dialect.quote_ident(&self.table_name.to_snake_case()),
+            self.dialect.quote_ident(col),
+            self.dialect.now()
+        );
 
-            for clause in &self.where_clauses {
-                clause(&mut query, &mut args, &self.driver, &mut arg_counter);
-            }
+        let mut args = AnyArguments::default();
+        let mut arg_counter = 1;
 
-            // Print SQL query to logs if debug mode is active
-            if self.debug_mode {
-                log::debug!("SQL: {}", query);
-            }
+        let redacted = self.compile_where(&mut query, &mut args, &mut arg_counter, !self.with_deleted);
+        let compiled = CompiledQuery { sql: query, args, redacted };
+        self.log_sql(&compiled);
 
-            let result = sqlx::query_with(&query, args).execute(self.tx.executor()).await?;
-            Ok(result.rows_affected())
-        }
+        self.execute_compiled(compiled).await
     }
 
     /// Permanently removes records from the database.
@@ -2069,7 +3212,7 @@ where
     /// # Returns
     ///
     /// * `Ok(u64)` - The number of rows deleted
-    /// * `Err(sqlx::Error)` - Database error
+    /// * `Err(Error)` - Database error
     ///
     /// # Example
     ///
@@ -2081,24 +3224,432 @@ where
     ///     .hard_delete()
     ///     .await?;
     /// ```
-    pub async fn hard_delete(mut self) -> Result<u64, sqlx::Error> {
-        let mut query = String::from("DELETE FROM \"");
-        query.push_str(&self.table_name.to_snake_case());
-        query.push_str("\" WHERE 1=1");
+    pub async fn hard_delete(mut self) -> Result<u64, Error> {
+        self.take_error()?;
+
+        let mut query = String::from("DELETE FROM ");
+        query.push_str(&self.dialect.quote_ident(&self.table_name.to_snake_case()));
 
         let mut args = AnyArguments::default();
         let mut arg_counter = 1;
 
-        for clause in &self.where_clauses {
-            clause(&mut query, &mut args, &self.driver, &mut arg_counter);
+        // Soft-deleted rows are removed too
+        let redacted = self.compile_where(&mut query, &mut args, &mut arg_counter, false);
+        let compiled = CompiledQuery { sql: query, args, redacted };
+        self.log_sql(&compiled);
+
+        self.execute_compiled(compiled).await
+    }
+
+    // ========================================================================
+    // Active Record Support
+    // ========================================================================
+
+    /// Inserts `model`, or overwrites the row with the same primary key.
+    ///
+    /// Runs as a single upsert on the primary key, so concurrent saves cannot
+    /// insert the row twice. Key and `create_time` columns of an existing row are kept.
+    pub(crate) fn upsert_by_key<'b>(&'b mut self, model: &'b T) -> BoxFuture<'b, Result<(), Error>> {
+        Box::pin(async move {
+            self.take_error()?;
+            let snake = |name: &str| name.strip_prefix("r#").unwrap_or(name).to_snake_case();
+
+            let keys: Vec<String> = T::primary_keys().iter().map(|k| snake(k)).collect();
+            if keys.is_empty() {
+                return Err(Error::InvalidArgument(format!("save() requires a primary key on `{}`", self.table_name)));
+            }
+
+            let data = Model::to_map(model);
+            let update: Vec<String> = data
+                .keys()
+                .filter(|col| {
+                    let column = self.columns_info.iter().find(|c| c.name == col.as_str());
+                    !column.is_some_and(|c| c.is_primary_key || c.create_time || c.generated.is_some())
+                })
+                .map(|col| snake(col))
+                .collect();
+
+            let compiled = self.compile_insert(data, Some(&keys), &update);
+            self.log_sql(&compiled);
+            self.execute_compiled(compiled).await?;
+            Ok(())
+        })
+    }
+
+    /// Inserts `model` and returns the value the database generated for the `key` column.
+    ///
+    /// The value is read through `RETURNING` where the dialect supports it, and
+    /// from the driver's last insert id otherwise (MySQL).
+    pub(crate) fn insert_returning_key<'b>(&'b mut self, model: &'b T, key: &str) -> BoxFuture<'b, Result<i64, Error>> {
+        let key = key.strip_prefix("r#").unwrap_or(key).to_snake_case();
+        Box::pin(async move {
+            self.take_error()?;
+            let mut compiled = self.compile_insert(Model::to_map(model), None, &[]);
+
+            match self.dialect.returning(&[&key]) {
+                Some(returning) => {
+                    compiled.sql.push_str(&returning);
+                    self.log_sql(&compiled);
+                    let row = self.fetch_optional_compiled(compiled).await?.ok_or(Error::NotFound)?;
+                    Ok(row.try_get::<i64, _>(0)?)
+                }
+                None => {
+                    self.log_sql(&compiled);
+                    let result = sqlx::query_with(&compiled.sql, compiled.args).execute(self.tx.executor()).await?;
+                    result.last_insert_id().ok_or_else(|| {
+                        Error::InvalidArgument(format!("no key was generated for `{}`", self.table_name))
+                    })
+                }
+            }
+        })
+    }
+
+    // ========================================================================
+    // Find or Create
+    // ========================================================================
+
+    /// Returns the row matching `filters`, inserting it first if there is none.
+    ///
+    /// Every field of `filters` adds a `column = value` condition on top of the
+    /// builder's own filters. When no row matches, `defaults` is inserted with the
+    /// filter values copied over it, and the new row is read back.
+    ///
+    /// Concurrent callers only avoid duplicates when the filter columns cover a
+    /// unique key (primary key, `#[orm(unique)]` column or unique index). The insert
+    /// is then an upsert that leaves an existing row untouched; MySQL instead runs
+    /// it in its own transaction (a savepoint inside `Transaction`) and retries a
+    /// unique violation as a lookup, since it reports a skipped duplicate as an
+    /// affected row. Without such a key, two callers can both insert the row;
+    /// [`strict()`](#method.strict) mode rejects these filters.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - Struct holding the identifying columns (usually `#[derive(FromAnyRow)]`)
+    /// * `defaults` - Model inserted when no row matches
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((T, bool))` - The row, and whether this call created it
+    /// * `Err(Error::InvalidArgument)` - `filters` is empty, names an unknown column, or
+    ///   covers no unique key in strict mode
+    /// * `Err(Error::UniqueViolation)` / `Err(Error::NotFound)` - The row conflicts with one
+    ///   the query cannot see (e.g. a soft-deleted row)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[derive(FromAnyRow)]
+    /// struct ByEmail {
+    ///     email: String,
+    /// }
+    ///
+    /// let defaults = User { id: 0, email: String::new(), name: "Anonymous".to_string() };
+    /// let (user, created) = db.model::<User>()
+    ///     .find_or_create(&ByEmail { email: "ada@example.com".to_string() }, &defaults)
+    ///     .await?;
+    /// ```
+    pub async fn find_or_create<P: AnyImpl>(mut self, filters: &P, defaults: &T) -> Result<(T, bool), Error>
+    where
+        T: FromAnyRow + AnyImpl + Send + Unpin,
+    {
+        let values = self.where_values("find_or_create", filters.to_map());
+        self.require_unique_key("find_or_create", &values);
+        self.take_error()?;
+        let conflict = self.unique_key(&values);
+
+        let mut data = Model::to_map(defaults);
+        data.extend(values);
+        self.first_or_insert_values(data, conflict.as_deref()).await
+    }
+
+    /// Updates the rows matching `filters` with `values`, inserting a row if there is none.
+    ///
+    /// Runs an `UPDATE` first and falls back to the insert of `find_or_create()`
+    /// (with the filter values copied over `values`). If another caller inserts the
+    /// row in between, the `UPDATE` is run once more. Primary key, filter and
+    /// `create_time` columns are never overwritten.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((T, bool))` - The updated or inserted row, and whether this call created it
+    /// * `Err(Error)` - Same as `find_or_create()`
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let values = Setting { key: String::new(), value: "dark".to_string() };
+    /// let (setting, created) = db.model::<Setting>()
+    ///     .update_or_create(&ByKey { key: "theme".to_string() }, &values)
+    ///     .await?;
+    /// ```
+    pub async fn update_or_create<P: AnyImpl>(mut self, filters: &P, values: &T) -> Result<(T, bool), Error>
+    where
+        T: FromAnyRow + AnyImpl + Send + Unpin,
+    {
+        let filter_values = self.where_values("update_or_create", filters.to_map());
+        self.require_unique_key("update_or_create", &filter_values);
+        self.take_error()?;
+        let conflict = self.unique_key(&filter_values);
+
+        let mut updates = Model::to_map(values);
+        updates.retain(|col, _| {
+            !filter_values.contains_key(col)
+                && !self.columns_info.iter().any(|c| c.name == col && (c.is_primary_key || c.create_time))
+        });
+
+        let mut data = Model::to_map(values);
+        data.extend(filter_values);
+        if updates.is_empty() {
+            return self.first_or_insert_values(data, conflict.as_deref()).await;
         }
 
-        // Print SQL query to logs if debug mode is active
-        if self.debug_mode {
-            log::debug!("SQL: {}", query);
+        let mut conflict_error = Error::NotFound;
+        for _ in 0..2 {
+            if self.execute_update(updates.clone()).await? > 0 {
+                let row = self.fetch_first().await?.ok_or(Error::NotFound)?;
+                return Ok((row, false));
+            }
+            match self.try_insert(data.clone(), conflict.as_deref()).await? {
+                Ok(()) => {
+                    let row = self.fetch_first().await?.ok_or(Error::NotFound)?;
+                    return Ok((row, true));
+                }
+                // Another caller inserted the row first, update it on the next pass
+                Err(e) => conflict_error = e,
+            }
+        }
+        Err(conflict_error)
+    }
+
+    /// Returns the first row matching the query, inserting `model` if there is none.
+    ///
+    /// The builder's filters cannot serve as an upsert target, so the insert runs
+    /// in its own transaction (a savepoint inside `Transaction`) and a unique
+    /// violation is retried as a lookup. `model` should satisfy the filters, as the
+    /// inserted row is read back through them.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((T, bool))` - The row, and whether this call created it
+    /// * `Err(Error)` - Database error, or the row conflicts with one the query cannot see
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let (tag, created) = db.model::<Tag>()
+    ///     .filter("name", Op::Eq, "rust".to_string())
+    ///     .first_or_insert(&Tag { id: 0, name: "rust".to_string() })
+    ///     .await?;
+    /// ```
+    pub async fn first_or_insert(mut self, model: &T) -> Result<(T, bool), Error>
+    where
+        T: FromAnyRow + AnyImpl + Send + Unpin,
+    {
+        self.take_error()?;
+        self.first_or_insert_values(Model::to_map(model), None).await
+    }
+
+    /// Shared implementation of `find_or_create()` and `first_or_insert()`.
+    async fn first_or_insert_values(
+        &mut self,
+        data: HashMap<String, String>,
+        conflict: Option<&[String]>,
+    ) -> Result<(T, bool), Error>
+    where
+        T: FromAnyRow + AnyImpl + Send + Unpin,
+    {
+        if let Some(row) = self.fetch_first().await? {
+            return Ok((row, false));
+        }
+
+        if let Err(conflict_error) = self.try_insert(data, conflict).await? {
+            // Another caller inserted the row first
+            return self.fetch_first().await?.map(|row| (row, false)).ok_or(conflict_error);
         }
 
-        let result = sqlx::query_with(&query, args).execute(self.tx.executor()).await?;
-        Ok(result.rows_affected())
+        let row = self.fetch_first().await?.ok_or(Error::NotFound)?;
+        Ok((row, true))
     }
+
+    /// Inserts `data`, returning the conflict with an existing row as the inner error.
+    ///
+    /// With a conflict target the statement is an upsert that skips the conflicting
+    /// row. Without one it runs in its own transaction, so that a unique violation
+    /// does not abort an enclosing PostgreSQL transaction.
+    fn try_insert<'b>(
+        &'b mut self,
+        data: HashMap<String, String>,
+        conflict: Option<&'b [String]>,
+    ) -> BoxFuture<'b, Result<Result<(), Error>, Error>> {
+        Box::pin(async move {
+            let compiled = self.compile_insert(data, conflict, &[]);
+            self.log_sql(&compiled);
+
+            if conflict.is_some() {
+                let affected = self.execute_compiled(compiled).await?;
+                return Ok(if affected > 0 { Ok(()) } else { Err(Error::NotFound) });
+            }
+
+            let mut tx = sqlx::Acquire::begin(self.tx.executor()).await?;
+            match sqlx::query_with(&compiled.sql, compiled.args).execute(&mut *tx).await.map_err(Error::from) {
+                Ok(_) => {
+                    tx.commit().await?;
+                    Ok(Ok(()))
+                }
+                Err(e @ Error::UniqueViolation { .. }) => {
+                    tx.rollback().await?;
+                    Ok(Err(e))
+                }
+                Err(e) => Err(e),
+            }
+        })
+    }
+
+    /// Compiles an `INSERT` of `data` with an upsert clause on the `conflict` columns.
+    ///
+    /// Conflicting rows get the `update` columns overwritten, or are skipped when
+    /// `update` is empty.
+    fn compile_insert(
+        &self,
+        data: HashMap<String, String>,
+        conflict: Option<&[String]>,
+        update: &[String],
+    ) -> CompiledQuery {
+        let mut columns = Vec::new();
+        let mut placeholders = Vec::new();
+        let mut args = AnyArguments::default();
+        let mut redacted = Vec::new();
+
+        for (col_name, value) in data {
+            let column = self.columns_info.iter().find(|c| c.name == col_name);
+            let col_name_clean = col_name.strip_prefix("r#").unwrap_or(&col_name).to_snake_case();
+
+            // Generated and auto-increment columns are assigned by the database,
+            // unless an auto-increment key is the conflict target
+            let key = conflict.is_some_and(|k| k.contains(&col_name_clean));
+            if column.is_some_and(|c| c.generated.is_some() || (c.auto_increment && !key)) {
+                continue;
+            }
+
+            let sql_type = column.map(|c| c.sql_type).unwrap_or("TEXT");
+            if column.is_some_and(|c| c.sensitive) {
+                redacted.push(args.len());
+            }
+            placeholders.push(self.dialect.cast_placeholder(self.dialect.placeholder(args.len() + 1), sql_type));
+            columns.push(self.dialect.quote_ident(&col_name_clean));
+            if self.dialect.bind_value(&mut args, &value, sql_type).is_err() {
+                let _ = args.add(value);
+            }
+        }
+
+        let mut sql = format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            self.dialect.quote_ident(&self.table_name.to_snake_case()),
+            columns.join(", "),
+            placeholders.join(", ")
+        );
+        if let Some(conflict) = conflict {
+            let conflict: Vec<&str> = conflict.iter().map(String::as_str).collect();
+            let update: Vec<&str> = update.iter().map(String::as_str).collect();
+            sql.push_str(&self.dialect.upsert(&conflict, &update));
+        }
+        CompiledQuery { sql, args, redacted }
+    }
+
+    /// Adds `column = value` for every entry of `filters`, returning the values keyed by model field.
+    ///
+    /// Records an error when `filters` is empty or names a column the model does not have.
+    fn where_values(&mut self, method: &str, filters: HashMap<String, String>) -> HashMap<String, String> {
+        let mut values = HashMap::new();
+        let table = self.table_name.to_snake_case();
+
+        for (field, value) in filters {
+            let name = field.strip_prefix("r#").unwrap_or(&field).to_snake_case();
+            let Some(column) =
+                self.columns_info.iter().find(|c| c.name.strip_prefix("r#").unwrap_or(c.name).to_snake_case() == name)
+            else {
+                self.set_error(Error::InvalidArgument(format!(
+                    "{}(): `{}` is not a column of `{}`",
+                    method, field, self.table_name
+                )));
+                continue;
+            };
+
+            let (table, sql_type, bound) = (table.clone(), column.sql_type, value.clone());
+            let clause: FilterFn = Box::new(move |query, args, dialect, arg_counter| {
+                let placeholder = dialect.cast_placeholder(dialect.placeholder(*arg_counter), sql_type);
+                *arg_counter += 1;
+                let column = format!("{}.{}", dialect.quote_ident(&table), dialect.quote_ident(&name));
+                query.push_str(&format!(" AND {} = {}", column, placeholder));
+
+                if dialect.bind_value(args, &bound, sql_type).is_err() {
+                    let _ = args.add(bound.clone());
+                }
+            });
+
+            if column.sensitive {
+                self.sensitive_filters.push(self.where_clauses.len());
+            }
+            self.where_clauses.push(clause);
+            values.insert(column.name.to_string(), value);
+        }
+
+        if values.is_empty() {
+            self.set_error(Error::InvalidArgument(format!("{}() requires at least one filter", method)));
+        }
+        values
+    }
+
+    /// Returns the upsert conflict target for the filter columns, if they cover a unique key.
+    fn unique_key(&self, values: &HashMap<String, String>) -> Option<Vec<String>> {
+        // If a skipped duplicate counts as an affected row, an upsert cannot tell
+        // whether the row was created
+        if self.dialect.upsert_reports_skipped_rows() {
+            return None;
+        }
+        self.covered_unique_key(values)
+    }
+
+    /// Returns the columns of a unique key that is fully covered by the filter columns.
+    ///
+    /// Primary keys, `#[orm(unique)]` columns and non-partial unique indexes count.
+    fn covered_unique_key(&self, values: &HashMap<String, String>) -> Option<Vec<String>> {
+        let snake = |name: &str| name.strip_prefix("r#").unwrap_or(name).to_snake_case();
+        let filters: Vec<String> = values.keys().map(|k| snake(k)).collect();
+
+        let mut keys: Vec<Vec<String>> = vec![T::primary_keys().iter().map(|c| snake(c)).collect()];
+        keys.extend(self.columns_info.iter().filter(|c| c.unique).map(|c| vec![snake(c.name)]));
+        keys.extend(
+            T::indexes()
+                .iter()
+                .filter(|i| i.unique && i.predicate.is_none())
+                .map(|i| i.columns.iter().map(|c| snake(c.name)).collect()),
+        );
+
+        keys.into_iter().find(|key| !key.is_empty() && key.iter().all(|c| filters.contains(c)))
+    }
+
+    /// Rejects filters that do not cover a unique key in strict mode.
+    ///
+    /// Without a unique key the database cannot stop two callers from inserting
+    /// the same row.
+    fn require_unique_key(&mut self, method: &str, values: &HashMap<String, String>) {
+        if self.strict && self.covered_unique_key(values).is_none() {
+            self.set_error(Error::InvalidArgument(format!(
+                "{}() filters must cover a primary key, unique column or unique index in strict mode",
+                method
+            )));
+        }
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Returns `true` if `name` is a plain SQL identifier (`[A-Za-z_][A-Za-z0-9_]*`).
+pub(crate) fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
 }
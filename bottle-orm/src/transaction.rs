@@ -24,11 +24,26 @@
 //! // Commit changes
 //! tx.commit().await?;
 //! ```
+//!
+//! Or let `Database::transaction()` commit, roll back and retry for you:
+//!
+//! ```rust,ignore
+//! use bottle_orm::RetryPolicy;
+//!
+//! let user_id = db.transaction_with_retry(RetryPolicy::new(3), |tx| Box::pin(async move {
+//!     tx.model::<User>().insert(&user).await?;
+//!     tx.model::<Post>().insert(&post).await?;
+//!     Ok::<_, bottle_orm::Error>(user.id)
+//! })).await?;
+//! ```
 
 // ============================================================================
 // External Crate Imports
 // ============================================================================
 
+use std::{panic::AssertUnwindSafe, time::Duration};
+
+use futures::{future::BoxFuture, FutureExt};
 use heck::ToSnakeCase;
 
 // ============================================================================
@@ -36,8 +51,9 @@ use heck::ToSnakeCase;
 // ============================================================================
 
 use crate::{
-    database::{Connection, Drivers, RawQuery},
-    Model, QueryBuilder,
+    database::{Connection, Database, ModelConnection, RawQuery},
+    migration::Migrator,
+    Error, Model, QueryBuilder,
 };
 
 // ============================================================================
@@ -56,11 +72,11 @@ use crate::{
 /// # Fields
 ///
 /// * `tx` - The underlying SQLx transaction
-/// * `driver` - The database driver type (for query syntax handling)
+/// * `db` - The database the transaction was started from (driver and dialect)
 #[derive(Debug)]
 pub struct Transaction<'a> {
     pub(crate) tx: sqlx::Transaction<'a, sqlx::Any>,
-    pub(crate) driver: Drivers,
+    pub(crate) db: Database,
 }
 
 // ============================================================================
@@ -78,6 +94,8 @@ impl<'a> Connection for Transaction<'a> {
     where
         Self: 'c;
 
+    const IS_TRANSACTION: bool = true;
+
     fn executor<'c>(&'c mut self) -> Self::Exec<'c> {
         &mut *self.tx
     }
@@ -90,11 +108,25 @@ impl<'a, 'b> Connection for &'a mut Transaction<'b> {
     where
         Self: 'c;
 
+    const IS_TRANSACTION: bool = true;
+
     fn executor<'c>(&'c mut self) -> Self::Exec<'c> {
         (**self).executor()
     }
 }
 
+/// Queries run inside the transaction.
+impl<'a> ModelConnection for &mut Transaction<'a> {
+    type Conn<'c>
+        = &'c mut sqlx::Transaction<'a, sqlx::Any>
+    where
+        Self: 'c;
+
+    fn model<T: Model + Send + Sync + Unpin>(&mut self) -> QueryBuilder<'_, T, Self::Conn<'_>> {
+        Transaction::model(self)
+    }
+}
+
 // ============================================================================
 // Transaction Implementation
 // ============================================================================
@@ -130,7 +162,7 @@ impl<'a> Transaction<'a> {
     /// ```
     pub fn model<T: Model + Send + Sync + Unpin>(
         &mut self,
-    ) -> QueryBuilder<'a, T, &mut sqlx::Transaction<'a, sqlx::Any>> {
+    ) -> QueryBuilder<'_, T, &mut sqlx::Transaction<'a, sqlx::Any>> {
         // Get active column names from the model
         let active_columns = T::active_columns();
         let mut columns: Vec<String> = Vec::with_capacity(active_columns.capacity());
@@ -141,7 +173,7 @@ impl<'a> Transaction<'a> {
         }
 
         // Create and return the query builder
-        QueryBuilder::new(&mut self.tx, self.driver, T::table_name(), T::columns(), columns)
+        QueryBuilder::new(&mut self.tx, self.db.dialect.clone(), T::table_name(), T::columns(), columns)
     }
 
     /// Creates a raw SQL query builder attached to this transaction.
@@ -173,21 +205,151 @@ impl<'a> Transaction<'a> {
         RawQuery::new(self, sql)
     }
 
+    // ========================================================================
+    // Schema Management
+    // ========================================================================
+
+    /// Creates the table for model `T` inside this transaction.
+    ///
+    /// Generates the same DDL as `Database::create_table()`. On engines with
+    /// transactional DDL (PostgreSQL, SQLite) the table disappears again if the
+    /// transaction is rolled back; MySQL commits DDL statements implicitly.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&mut Self)` - Reference to self for method chaining
+    /// * `Err(Error)` - Database error during table creation
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut tx = db.begin().await?;
+    /// tx.create_table::<User>().await?;
+    /// tx.model::<User>().insert(&admin).await?;
+    /// tx.commit().await?; // table and row appear together
+    /// ```
+    pub async fn create_table<T: Model>(&mut self) -> Result<&mut Self, Error> {
+        self.db.create_table_on::<T>(&mut self.tx).await?;
+        Ok(self)
+    }
+
+    /// Assigns the foreign key constraints of model `T` inside this transaction.
+    ///
+    /// The transactional counterpart of `Database::assign_foreign_keys()`.
+    pub async fn assign_foreign_keys<T: Model>(&mut self) -> Result<&mut Self, Error> {
+        self.db.assign_foreign_keys_on::<T>(&mut self.tx).await?;
+        Ok(self)
+    }
+
+    /// Creates a `Migrator` that runs inside this transaction.
+    ///
+    /// All phases execute on the transaction's connection and nothing is
+    /// committed until you call `commit()`, so schema changes can be applied
+    /// atomically together with data changes. On PostgreSQL the migration lock
+    /// is a transaction-scoped advisory lock; MySQL commits DDL implicitly, so
+    /// prefer `Database::migrator()` there.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut tx = db.begin().await?;
+    ///
+    /// tx.migrator()
+    ///     .register::<User>()
+    ///     .register::<Post>()
+    ///     .run()
+    ///     .await?;
+    /// tx.model::<User>().insert(&admin).await?;
+    ///
+    /// tx.commit().await?;
+    /// ```
+    pub fn migrator(&mut self) -> Migrator<'_> {
+        Migrator::in_transaction(&self.db, &mut self.tx)
+    }
+
     // ========================================================================
     // Transaction Control
     // ========================================================================
 
+    /// Starts a nested transaction backed by a savepoint.
+    ///
+    /// The returned handle works like a `Transaction`: `commit()` releases the
+    /// savepoint (`RELEASE SAVEPOINT`), keeping its changes as part of the outer
+    /// transaction, and `rollback()` or dropping it undoes only the changes made
+    /// since the savepoint (`ROLLBACK TO SAVEPOINT`). Savepoints can be nested.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Transaction)` - The nested transaction, borrowing this one
+    /// * `Err(Error)` - Database error creating the savepoint
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut tx = db.begin().await?;
+    /// tx.model::<Order>().insert(&order).await?;
+    ///
+    /// let mut nested = tx.savepoint().await?;
+    /// if nested.model::<Coupon>().insert(&coupon).await.is_err() {
+    ///     nested.rollback().await?; // the order is kept
+    /// } else {
+    ///     nested.commit().await?;
+    /// }
+    ///
+    /// tx.commit().await?;
+    /// ```
+    pub async fn savepoint(&mut self) -> Result<Transaction<'_>, Error> {
+        let tx = sqlx::Acquire::begin(&mut self.tx).await?;
+        Ok(Transaction { tx, db: self.db.clone() })
+    }
+
+    /// Starts a nested transaction; an alias of [`savepoint()`](Self::savepoint).
+    ///
+    /// Lets code written against `Database::begin()` compose inside an
+    /// existing transaction.
+    pub async fn begin(&mut self) -> Result<Transaction<'_>, Error> {
+        self.savepoint().await
+    }
+
+    /// Runs a closure inside a savepoint of this transaction.
+    ///
+    /// The nested counterpart of `Database::transaction()`: the savepoint is
+    /// released when the closure returns `Ok` and rolled back when it returns
+    /// `Err` or panics, leaving the outer transaction usable either way.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// async fn create_post(tx: &mut Transaction<'_>, post: Post) -> Result<(), bottle_orm::Error> {
+    ///     tx.transaction(|sp| Box::pin(async move {
+    ///         sp.model::<Post>().insert(&post).await?;
+    ///         sp.model::<Tag>().insert(&tag).await?;
+    ///         Ok(())
+    ///     })).await
+    /// }
+    /// ```
+    pub async fn transaction<'b, T, E, F>(&'b mut self, mut f: F) -> Result<T, E>
+    where
+        F: for<'t> FnMut(&'t mut Transaction<'b>) -> BoxFuture<'t, Result<T, E>> + Send,
+        T: Send,
+        E: From<Error> + Send,
+    {
+        let savepoint = self.savepoint().await?;
+        run_in(savepoint, &mut f).await
+    }
+
     /// Commits the transaction.
     ///
     /// Persists all changes made during the transaction to the database.
-    /// This consumes the `Transaction` instance.
+    /// This consumes the `Transaction` instance. On a nested transaction from
+    /// `savepoint()`, this releases the savepoint instead.
     ///
     /// # Returns
     ///
     /// * `Ok(())` - Transaction committed successfully
-    /// * `Err(sqlx::Error)` - Database error during commit
-    pub async fn commit(self) -> Result<(), sqlx::Error> {
-        self.tx.commit().await
+    /// * `Err(Error)` - Database error during commit
+    pub async fn commit(self) -> Result<(), Error> {
+        Ok(self.tx.commit().await?)
     }
 
     /// Rolls back the transaction.
@@ -199,8 +361,198 @@ impl<'a> Transaction<'a> {
     /// # Returns
     ///
     /// * `Ok(())` - Transaction rolled back successfully
-    /// * `Err(sqlx::Error)` - Database error during rollback
-    pub async fn rollback(self) -> Result<(), sqlx::Error> {
-        self.tx.rollback().await
+    /// * `Err(Error)` - Database error during rollback
+    pub async fn rollback(self) -> Result<(), Error> {
+        Ok(self.tx.rollback().await?)
+    }
+}
+
+// ============================================================================
+// Transaction Options
+// ============================================================================
+
+/// SQL transaction isolation level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsolationLevel {
+    /// `READ UNCOMMITTED` (treated as `READ COMMITTED` by PostgreSQL)
+    ReadUncommitted,
+    /// `READ COMMITTED`
+    ReadCommitted,
+    /// `REPEATABLE READ`
+    RepeatableRead,
+    /// `SERIALIZABLE`
+    Serializable,
+}
+
+impl IsolationLevel {
+    /// Converts the isolation level to its SQL representation.
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+            IsolationLevel::ReadCommitted => "READ COMMITTED",
+            IsolationLevel::RepeatableRead => "REPEATABLE READ",
+            IsolationLevel::Serializable => "SERIALIZABLE",
+        }
+    }
+}
+
+/// Options for `Database::begin_with()`.
+///
+/// The default uses the server's isolation level in read-write mode, like
+/// `Database::begin()`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use bottle_orm::{IsolationLevel, TxOptions};
+///
+/// let report = TxOptions {
+///     isolation: Some(IsolationLevel::Serializable),
+///     read_only: true,
+///     deferrable: true,
+/// };
+/// let mut tx = db.begin_with(report).await?;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TxOptions {
+    /// Isolation level; `None` keeps the server default
+    pub isolation: Option<IsolationLevel>,
+    /// Rejects writes for the duration of the transaction; not supported on SQLite
+    pub read_only: bool,
+    /// PostgreSQL only: a `SERIALIZABLE READ ONLY` transaction waits for a
+    /// snapshot that cannot cause serialization failures
+    pub deferrable: bool,
+}
+
+// ============================================================================
+// Closure Transactions
+// ============================================================================
+
+/// Retry behavior for `Database::transaction_with_retry()`.
+///
+/// A transaction is retried when it fails with a serialization failure or a
+/// deadlock: SQLSTATE `40001` / `40P01` (PostgreSQL), error 1213 (MySQL) or
+/// `SQLITE_BUSY`. Retries wait with exponential backoff, starting at `backoff`
+/// and doubling up to `max_backoff`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new(5)
+///     .backoff(Duration::from_millis(20))
+///     .max_backoff(Duration::from_millis(500));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub(crate) max_retries: u32,
+    pub(crate) backoff: Duration,
+    pub(crate) max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates a policy that retries up to `max_retries` times (10ms initial backoff, 1s maximum).
+    pub fn new(max_retries: u32) -> Self {
+        Self { max_retries, backoff: Duration::from_millis(10), max_backoff: Duration::from_secs(1) }
+    }
+
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self::new(0)
+    }
+
+    /// Sets the wait before the first retry.
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Sets the upper bound of the wait between retries.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Returns the wait before retry number `attempt` (0-based).
+    fn delay(&self, attempt: u32) -> Duration {
+        self.backoff.saturating_mul(2u32.saturating_pow(attempt)).min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+/// Runs `f` in a new transaction, committing on `Ok` and rolling back on `Err` or panic.
+pub(crate) async fn run<T, E, F>(db: &Database, policy: RetryPolicy, mut f: F) -> Result<T, E>
+where
+    F: for<'t> FnMut(&'t mut Transaction<'static>) -> BoxFuture<'t, Result<T, E>> + Send,
+    T: Send,
+    E: From<Error> + std::error::Error + Send + 'static,
+{
+    let mut attempt = 0;
+    loop {
+        let tx = db.begin().await?;
+        let error = match run_in(tx, &mut f).await {
+            Ok(value) => return Ok(value),
+            Err(e) => e,
+        };
+
+        if attempt >= policy.max_retries || !is_retryable(&error) {
+            return Err(error);
+        }
+        log::debug!("Retrying transaction after error: {}", error);
+        tokio::time::sleep(policy.delay(attempt)).await;
+        attempt += 1;
+    }
+}
+
+/// Runs `f` once in `tx`, committing on `Ok` and rolling back on `Err` or panic.
+async fn run_in<'a, T, E, F>(mut tx: Transaction<'a>, f: &mut F) -> Result<T, E>
+where
+    F: for<'t> FnMut(&'t mut Transaction<'a>) -> BoxFuture<'t, Result<T, E>> + Send,
+    T: Send,
+    E: From<Error> + Send,
+{
+    match AssertUnwindSafe(f(&mut tx)).catch_unwind().await {
+        Ok(Ok(value)) => {
+            tx.commit().await?;
+            Ok(value)
+        }
+        Ok(Err(e)) => {
+            // The closure's error is more useful than a failed rollback
+            let _ = tx.rollback().await;
+            Err(e)
+        }
+        Err(panic) => {
+            let _ = tx.rollback().await;
+            std::panic::resume_unwind(panic);
+        }
+    }
+}
+
+/// Returns `true` if `error`, or any error in its source chain, is a serialization failure or deadlock.
+pub(crate) fn is_retryable(error: &(dyn std::error::Error + 'static)) -> bool {
+    let mut current = Some(error);
+    while let Some(e) = current {
+        if let Some(sqlx::Error::Database(db_error)) = e.downcast_ref::<sqlx::Error>() {
+            if let Some(mysql) = db_error.try_downcast_ref::<sqlx::mysql::MySqlDatabaseError>() {
+                return mysql.number() == 1213;
+            }
+            return match db_error.code().as_deref() {
+                // PostgreSQL serialization_failure / deadlock_detected
+                Some("40001") | Some("40P01") => true,
+                // SQLite reports extended result codes; the primary code is the low byte
+                Some(code) if db_error.try_downcast_ref::<sqlx::sqlite::SqliteError>().is_some() => {
+                    code.parse::<i32>().is_ok_and(|c| c & 0xff == 5)
+                }
+                _ => false,
+            };
+        }
+        current = e.source();
     }
+    false
 }
@@ -210,7 +210,7 @@ impl ValueBinder for AnyArguments<'_> {
             // ================================================================
             // Text and Default Types
             // ================================================================
-            "TEXT" | "VARCHAR" | "CHAR" | "STRING" | _ => {
+            _ => {
                 self.bind_string(value_str.to_string());
                 Ok(())
             }
@@ -328,7 +328,7 @@ pub fn bind_typed_value(
 /// * `sql_type` - SQL type identifier
 /// * `driver` - Database driver
 pub fn bind_typed_value_or_string(args: &mut AnyArguments<'_>, value_str: &str, sql_type: &str, driver: &Drivers) {
-    if let Err(_) = args.bind_value(value_str, sql_type, driver) {
+    if args.bind_value(value_str, sql_type, driver).is_err() {
         // Fallback: bind as string
         let _ = args.add(value_str.to_string());
     }
@@ -0,0 +1,220 @@
+//! # Window Function Module
+//!
+//! This module provides the `Window` builder for SQL window functions such as
+//! rankings, running totals and row offsets, rendered as
+//! `FUNCTION(...) OVER (PARTITION BY ... ORDER BY ... frame)`.
+//!
+//! Window expressions are added to a query with `QueryBuilder::select_expr()`
+//! under an alias, so they decode into the field of the same name of a
+//! `FromAnyRow` struct.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use bottle_orm::{Direction, FromAnyRow, Window};
+//!
+//! #[derive(FromAnyRow)]
+//! struct RankedOrder {
+//!     id: i32,
+//!     user_id: i32,
+//!     position: i64,
+//! }
+//!
+//! // SELECT "id", "user_id",
+//! //   ROW_NUMBER() OVER (PARTITION BY "user_id" ORDER BY "created_at" ASC) AS "position"
+//! let orders: Vec<RankedOrder> = db.model::<Order>()
+//!     .select("id")
+//!     .select("user_id")
+//!     .select_expr(
+//!         Window::row_number().partition_by("user_id").order_by("created_at", Direction::Asc),
+//!         "position",
+//!     )
+//!     .scan()
+//!     .await?;
+//! ```
+
+// ============================================================================
+// Internal Crate Imports
+// ============================================================================
+
+use crate::query_builder::Direction;
+
+// ============================================================================
+// Frame Bounds
+// ============================================================================
+
+/// One end of a window frame (`ROWS BETWEEN start AND end`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameBound {
+    /// `UNBOUNDED PRECEDING` - the first row of the partition
+    UnboundedPreceding,
+    /// `n PRECEDING`
+    Preceding(u64),
+    /// `CURRENT ROW`
+    CurrentRow,
+    /// `n FOLLOWING`
+    Following(u64),
+    /// `UNBOUNDED FOLLOWING` - the last row of the partition
+    UnboundedFollowing,
+}
+
+impl FrameBound {
+    /// Converts the bound to its SQL representation.
+    pub fn as_sql(&self) -> String {
+        match self {
+            FrameBound::UnboundedPreceding => "UNBOUNDED PRECEDING".to_string(),
+            FrameBound::Preceding(n) => format!("{} PRECEDING", n),
+            FrameBound::CurrentRow => "CURRENT ROW".to_string(),
+            FrameBound::Following(n) => format!("{} FOLLOWING", n),
+            FrameBound::UnboundedFollowing => "UNBOUNDED FOLLOWING".to_string(),
+        }
+    }
+}
+
+// ============================================================================
+// Window Builder
+// ============================================================================
+
+/// The function evaluated over the window.
+#[derive(Debug, Clone)]
+enum WindowFunction {
+    RowNumber,
+    Rank,
+    DenseRank,
+    Lag { column: String, offset: u64 },
+    Lead { column: String, offset: u64 },
+    Aggregate { function: &'static str, column: String },
+}
+
+/// A window function expression: `FUNCTION(...) OVER (...)`.
+///
+/// Construct with one of the function constructors, then refine the window
+/// with `partition_by`, `order_by` and a frame. Column names are quoted for the
+/// active dialect and validated when the query is in strict mode.
+#[derive(Debug, Clone)]
+pub struct Window {
+    function: WindowFunction,
+    partition_by: Vec<String>,
+    order_by: Vec<(String, Direction)>,
+    frame: Option<(&'static str, FrameBound, FrameBound)>,
+}
+
+impl Window {
+    fn new(function: WindowFunction) -> Self {
+        Self { function, partition_by: Vec::new(), order_by: Vec::new(), frame: None }
+    }
+
+    /// `ROW_NUMBER()` - sequential number of the row within its partition.
+    pub fn row_number() -> Self {
+        Self::new(WindowFunction::RowNumber)
+    }
+
+    /// `RANK()` - rank with gaps after ties.
+    pub fn rank() -> Self {
+        Self::new(WindowFunction::Rank)
+    }
+
+    /// `DENSE_RANK()` - rank without gaps after ties.
+    pub fn dense_rank() -> Self {
+        Self::new(WindowFunction::DenseRank)
+    }
+
+    /// `LAG(column, offset)` - value of `column` from `offset` rows before the current row.
+    pub fn lag(column: &str, offset: u64) -> Self {
+        Self::new(WindowFunction::Lag { column: column.to_string(), offset })
+    }
+
+    /// `LEAD(column, offset)` - value of `column` from `offset` rows after the current row.
+    pub fn lead(column: &str, offset: u64) -> Self {
+        Self::new(WindowFunction::Lead { column: column.to_string(), offset })
+    }
+
+    /// `SUM(column) OVER (...)` - e.g. a running total when combined with `order_by`.
+    pub fn sum(column: &str) -> Self {
+        Self::new(WindowFunction::Aggregate { function: "SUM", column: column.to_string() })
+    }
+
+    /// `AVG(column) OVER (...)` - e.g. a moving average when combined with a frame.
+    pub fn avg(column: &str) -> Self {
+        Self::new(WindowFunction::Aggregate { function: "AVG", column: column.to_string() })
+    }
+
+    /// `COUNT(column) OVER (...)`.
+    pub fn count(column: &str) -> Self {
+        Self::new(WindowFunction::Aggregate { function: "COUNT", column: column.to_string() })
+    }
+
+    /// `MIN(column) OVER (...)`.
+    pub fn min(column: &str) -> Self {
+        Self::new(WindowFunction::Aggregate { function: "MIN", column: column.to_string() })
+    }
+
+    /// `MAX(column) OVER (...)`.
+    pub fn max(column: &str) -> Self {
+        Self::new(WindowFunction::Aggregate { function: "MAX", column: column.to_string() })
+    }
+
+    /// Adds a `PARTITION BY` column. Call repeatedly to partition by several columns.
+    pub fn partition_by(mut self, column: &str) -> Self {
+        self.partition_by.push(column.to_string());
+        self
+    }
+
+    /// Adds an `ORDER BY` entry to the window. Call repeatedly for several entries.
+    pub fn order_by(mut self, column: &str, direction: Direction) -> Self {
+        self.order_by.push((column.to_string(), direction));
+        self
+    }
+
+    /// Sets a `ROWS BETWEEN start AND end` frame.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Moving average over the current and two previous rows
+    /// Window::avg("amount")
+    ///     .order_by("created_at", Direction::Asc)
+    ///     .rows_between(FrameBound::Preceding(2), FrameBound::CurrentRow)
+    /// ```
+    pub fn rows_between(mut self, start: FrameBound, end: FrameBound) -> Self {
+        self.frame = Some(("ROWS", start, end));
+        self
+    }
+
+    /// Sets a `RANGE BETWEEN start AND end` frame.
+    pub fn range_between(mut self, start: FrameBound, end: FrameBound) -> Self {
+        self.frame = Some(("RANGE", start, end));
+        self
+    }
+
+    /// Renders the expression, mapping every column name through `column`.
+    ///
+    /// `column` is supplied by the query builder and quotes (and, in strict
+    /// mode, validates) each identifier.
+    pub(crate) fn render(&self, column: &mut dyn FnMut(&str) -> String) -> String {
+        let function = match &self.function {
+            WindowFunction::RowNumber => "ROW_NUMBER()".to_string(),
+            WindowFunction::Rank => "RANK()".to_string(),
+            WindowFunction::DenseRank => "DENSE_RANK()".to_string(),
+            WindowFunction::Lag { column: c, offset } => format!("LAG({}, {})", column(c), offset),
+            WindowFunction::Lead { column: c, offset } => format!("LEAD({}, {})", column(c), offset),
+            WindowFunction::Aggregate { function, column: c } => format!("{}({})", function, column(c)),
+        };
+
+        let mut over = Vec::new();
+        if !self.partition_by.is_empty() {
+            let partition: Vec<String> = self.partition_by.iter().map(|c| column(c)).collect();
+            over.push(format!("PARTITION BY {}", partition.join(", ")));
+        }
+        if !self.order_by.is_empty() {
+            let order: Vec<String> =
+                self.order_by.iter().map(|(c, direction)| format!("{} {}", column(c), direction.as_sql())).collect();
+            over.push(format!("ORDER BY {}", order.join(", ")));
+        }
+        if let Some((unit, start, end)) = &self.frame {
+            over.push(format!("{} BETWEEN {} AND {}", unit, start.as_sql(), end.as_sql()));
+        }
+
+        format!("{} OVER ({})", function, over.join(" "))
+    }
+}
@@ -0,0 +1,138 @@
+use bottle_orm::{Database, Error, Model, Op};
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Account {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+    balance: i64,
+    #[orm(soft_delete)]
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Note {
+    #[orm(primary_key, auto_increment)]
+    id: i64,
+    body: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Membership {
+    #[orm(primary_key)]
+    account_id: i32,
+    #[orm(primary_key)]
+    group_id: i32,
+    role: String,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Account>().register::<Membership>().register::<Note>().run().await?;
+    Ok(db)
+}
+
+fn account(id: i32, name: &str, balance: i64) -> Account {
+    Account { id, name: name.to_string(), balance, deleted_at: None }
+}
+
+#[tokio::test]
+async fn test_save_find_reload_delete_on_database() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let mut ada = account(1, "Ada", 100);
+    ada.save(&db).await?;
+    assert_eq!(Account::find(&db, 1).await?, Some(ada.clone()));
+
+    // Saving again updates the existing row instead of inserting a duplicate
+    ada.balance = 250;
+    ada.save(&db).await?;
+    assert_eq!(db.model::<Account>().count().await?, 1);
+    assert_eq!(Account::find(&db, 1).await?.map(|a| a.balance), Some(250));
+
+    db.model::<Account>().filter("id", Op::Eq, 1).update("name", "Ada Lovelace").await?;
+    ada.reload(&db).await?;
+    assert_eq!(ada.name, "Ada Lovelace");
+
+    // Soft delete hides the row from find(), but reload() still sees it
+    assert_eq!(ada.delete(&db).await?, 1);
+    assert_eq!(Account::find(&db, 1).await?, None);
+    ada.reload(&db).await?;
+    assert!(ada.deleted_at.is_some());
+
+    let mut ghost = account(42, "Ghost", 0);
+    assert!(matches!(ghost.reload(&db).await, Err(Error::NotFound)));
+
+    // Composite keys work for everything but find()
+    let mut member = Membership { account_id: 1, group_id: 7, role: "owner".to_string() };
+    member.save(&db).await?;
+    member.role = "admin".to_string();
+    member.save(&db).await?;
+    member.role = String::new();
+    member.reload(&db).await?;
+    assert_eq!(member.role, "admin");
+    assert_eq!(member.delete(&db).await?, 1);
+    assert!(matches!(Membership::find(&db, 1).await, Err(Error::InvalidArgument(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_active_record_in_transaction() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let mut tx = db.begin().await?;
+    let mut grace = account(2, "Grace", 10);
+    grace.save(&mut tx).await?;
+    assert_eq!(Account::find(&mut tx, 2).await?, Some(grace.clone()));
+    tx.rollback().await?;
+    assert_eq!(Account::find(&db, 2).await?, None);
+
+    let mut tx = db.begin().await?;
+    grace.save(&mut tx).await?;
+    let mut linus = account(3, "Linus", 5);
+    linus.save(&mut tx).await?;
+    linus.delete(&mut tx).await?;
+    linus.reload(&mut tx).await?;
+    tx.commit().await?;
+
+    assert!(linus.deleted_at.is_some());
+    assert_eq!(db.model::<Account>().count().await?, 1);
+    assert_eq!(db.model::<Account>().with_deleted().count().await?, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_save_writes_back_generated_key() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    // The first save inserts and fills in the key, the second updates that row
+    let mut note = Note { id: 0, body: "draft".to_string() };
+    note.save(&db).await?;
+    assert_eq!(note.id, 1);
+    note.body = "final".to_string();
+    note.save(&db).await?;
+    assert_eq!(db.model::<Note>().count().await?, 1);
+    assert_eq!(Note::find(&db, 1).await?.map(|n| n.body), Some("final".to_string()));
+
+    let mut tx = db.begin().await?;
+    let mut other = Note { id: 0, body: "other".to_string() };
+    other.save(&mut tx).await?;
+    other.save(&mut tx).await?;
+    tx.commit().await?;
+    assert_eq!(other.id, 2);
+    assert_eq!(db.model::<Note>().count().await?, 2);
+
+    // A soft-deleted row is updated in place rather than inserted again
+    let mut ada = account(1, "Ada", 100);
+    ada.save(&db).await?;
+    ada.delete(&db).await?;
+    ada.reload(&db).await?;
+    ada.name = "Ada Lovelace".to_string();
+    ada.save(&db).await?;
+    assert_eq!(db.model::<Account>().with_deleted().count().await?, 1);
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use bottle_orm::{
+    database::create_table_statements,
+    dialect::{MySqlDialect, PostgresDialect, SqliteDialect},
+    Database, Error, Model,
+};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Comment {
+    #[orm(primary_key, auto_increment)]
+    id: i64,
+    body: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+#[orm(primary_key(thread_id, seq))]
+struct Reply {
+    thread_id: i64,
+    #[orm(auto_increment)]
+    seq: i64,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Ticket {
+    #[orm(primary_key)]
+    code: String,
+    #[orm(auto_increment)]
+    number: i64,
+}
+
+#[tokio::test]
+async fn test_auto_increment_assigns_ids() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Comment>().run().await?;
+
+    // BIGINT is rewritten to INTEGER so SQLite accepts AUTOINCREMENT
+    let ddl: String =
+        db.raw("SELECT sql FROM sqlite_master WHERE type = 'table' AND name = 'comment'").fetch_scalar().await?;
+    assert!(ddl.contains("\"id\" INTEGER PRIMARY KEY AUTOINCREMENT"), "{}", ddl);
+
+    // The id on the struct is ignored, so repeated inserts don't collide
+    for body in ["first", "second"] {
+        db.model::<Comment>().insert(&Comment { id: 0, body: body.to_string() }).await?;
+    }
+
+    let comments: Vec<Comment> = db.model::<Comment>().order("id ASC").scan().await?;
+    let ids: Vec<i64> = comments.iter().map(|c| c.id).collect();
+    assert_eq!(ids, vec![1, 2]);
+    assert_eq!(comments[1].body, "second");
+
+    Ok(())
+}
+
+#[test]
+fn test_sqlite_rejects_auto_increment_outside_single_key() {
+    // SQLite can only auto-increment an inline INTEGER PRIMARY KEY
+    let results = [create_table_statements::<Reply>(&SqliteDialect), create_table_statements::<Ticket>(&SqliteDialect)];
+    for result in results {
+        assert!(matches!(result, Err(Error::InvalidArgument(_))), "{:?}", result);
+    }
+
+    // Other dialects can still express both
+    assert!(create_table_statements::<Reply>(&PostgresDialect).is_ok());
+    assert!(create_table_statements::<Ticket>(&MySqlDialect).is_ok());
+}
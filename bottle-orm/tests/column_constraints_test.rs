@@ -0,0 +1,90 @@
+use bottle_orm::{
+    database::create_table_statements,
+    dialect::{MySqlDialect, PostgresDialect, SqliteDialect},
+    Database, Model,
+};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct LineItem {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(check = "price >= 0")]
+    price: f64,
+    #[orm(default = 1)]
+    quantity: i32,
+    #[orm(default = "'pending'")]
+    status: Option<String>,
+    #[orm(generated = "price * quantity", stored)]
+    total: f64,
+}
+
+#[test]
+fn test_column_constraint_metadata() {
+    let columns = LineItem::columns();
+    let column = |name: &str| columns.iter().find(|c| c.name == name).unwrap().clone();
+
+    assert_eq!(column("price").check, Some("price >= 0"));
+    assert_eq!(column("quantity").default, Some("1"));
+    assert_eq!(column("status").default, Some("'pending'"));
+    assert_eq!(column("total").generated, Some("price * quantity"));
+    assert!(column("total").stored);
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Invoice {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(unique)]
+    net: i64,
+    #[orm(generated = "net * 2", unique)]
+    gross: i64,
+}
+
+#[test]
+fn test_generated_column_ddl_per_dialect() -> Result<(), Box<dyn std::error::Error>> {
+    let mysql = create_table_statements::<LineItem>(&MySqlDialect)?;
+    assert_eq!(
+        mysql[0],
+        "CREATE TABLE IF NOT EXISTS `line_item` (`id` INTEGER PRIMARY KEY, `price` DOUBLE NOT NULL CHECK (price >= 0), \
+         `quantity` INTEGER NOT NULL DEFAULT 1, `status` TEXT DEFAULT 'pending', \
+         `total` DOUBLE GENERATED ALWAYS AS (price * quantity) STORED NOT NULL)"
+    );
+
+    // The generation clause directly follows the type, before NOT NULL and UNIQUE
+    let mysql = &create_table_statements::<Invoice>(&MySqlDialect)?[0];
+    assert!(mysql.contains("`gross` BIGINT GENERATED ALWAYS AS (net * 2) VIRTUAL NOT NULL UNIQUE"), "{}", mysql);
+    let sqlite = &create_table_statements::<Invoice>(&SqliteDialect)?[0];
+    assert!(sqlite.contains("\"gross\" BIGINT GENERATED ALWAYS AS (net * 2) VIRTUAL NOT NULL UNIQUE"), "{}", sqlite);
+
+    // PostgreSQL before 18 only supports stored generated columns
+    let postgres = &create_table_statements::<Invoice>(&PostgresDialect)?[0];
+    assert!(postgres.contains("\"gross\" BIGINT GENERATED ALWAYS AS (net * 2) STORED NOT NULL UNIQUE"), "{}", postgres);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_defaults_checks_and_generated_columns() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<LineItem>().run().await?;
+
+    // `total` is computed by the database; the value on the struct is ignored
+    let item = LineItem { id: 1, price: 2.5, quantity: 4, status: None, total: 0.0 };
+    db.model::<LineItem>().insert(&item).await?;
+
+    let stored: LineItem = db.model::<LineItem>().first().await?;
+    assert_eq!(stored.total, 10.0);
+    assert_eq!(stored.status.as_deref(), Some("pending"));
+
+    // updates() skips the generated column and the database recomputes it
+    let changed = LineItem { quantity: 2, total: 999.0, ..stored };
+    db.model::<LineItem>().equals("id", 1).updates(&changed).await?;
+    let stored: LineItem = db.model::<LineItem>().first().await?;
+    assert_eq!(stored.total, 5.0);
+
+    // CHECK constraint rejects negative prices
+    let invalid = LineItem { id: 2, price: -1.0, quantity: 1, status: None, total: 0.0 };
+    assert!(db.model::<LineItem>().insert(&invalid).await.is_err());
+
+    Ok(())
+}
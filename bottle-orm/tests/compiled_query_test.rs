@@ -0,0 +1,87 @@
+use bottle_orm::{database::Connection, Database, FromAnyRow, Model, Op, Pagination};
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Task {
+    #[orm(primary_key)]
+    id: i32,
+    title: String,
+    priority: i32,
+
+    #[orm(soft_delete)]
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+#[tokio::test]
+async fn test_build_matches_execution() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+
+    let compiled = db.model::<Task>().filter("priority", Op::Gte, 2).order("id").limit(5).offset(1).build();
+    assert_eq!(
+        compiled.sql,
+        "SELECT * FROM \"task\" WHERE 1=1 AND \"deleted_at\" IS NULL AND \"priority\" >= ? ORDER BY id LIMIT ? OFFSET ?"
+    );
+    assert_eq!(compiled.len(), 3);
+
+    // to_sql() is the SQL part of build(); with_deleted() drops the soft delete filter
+    let sql = db.model::<Task>().with_deleted().to_sql();
+    assert_eq!(sql, "SELECT * FROM \"task\" WHERE 1=1");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_build_for_matches_scan() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Task>().run().await?;
+    db.model::<Task>().insert(&Task { id: 1, title: "a".to_string(), priority: 3, deleted_at: None }).await?;
+
+    // build_for() compiles the projection scan() derives from the result type
+    let query = db.model::<Task>().filter("priority", Op::Gte, 2);
+    let compiled = query.build_for::<Task>();
+    assert_eq!(
+        compiled.sql,
+        "SELECT \"task\".\"id\" AS \"task__id\", \"task\".\"title\" AS \"task__title\", \
+         \"task\".\"priority\" AS \"task__priority\", \"task\".\"deleted_at\" AS \"task__deleted_at\" \
+         FROM \"task\" WHERE 1=1 AND \"deleted_at\" IS NULL AND \"priority\" >= ?"
+    );
+    assert_ne!(compiled.sql, query.build().sql);
+
+    let mut conn = db.clone();
+    let rows = sqlx::query_with(&compiled.sql, compiled.args).fetch_all(conn.executor()).await?;
+    let built: Vec<Task> = rows.iter().map(Task::from_any_row).collect::<Result<_, _>>()?;
+    assert_eq!(built, query.scan::<Task>().await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_consistent_soft_delete_and_pagination() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Task>().run().await?;
+
+    for id in 1..=5 {
+        let task = Task { id, title: format!("task {}", id), priority: id % 3, deleted_at: None };
+        db.model::<Task>().insert(&task).await?;
+    }
+    db.model::<Task>().equals("id", 1).delete().await?;
+
+    // A second soft delete skips rows that are already deleted
+    assert_eq!(db.model::<Task>().equals("id", 1).delete().await?, 0);
+
+    // Count and data queries agree on the soft delete scope
+    let page = Pagination::new(0, 2).paginate::<Task, _, Task>(db.model::<Task>().order("id")).await?;
+    assert_eq!(page.total, 4);
+    assert_eq!(page.data.iter().map(|t| t.id).collect::<Vec<_>>(), vec![2, 3]);
+
+    let first: Task = db.model::<Task>().filter("priority", Op::Gte, 1).first().await?;
+    assert_eq!(first.id, 2);
+    assert_eq!(db.model::<Task>().count().await?, 4);
+
+    let compiled = db.model::<Task>().filter("priority", Op::Eq, 2).build();
+    let mut conn = db.clone();
+    let rows = sqlx::query_with(&compiled.sql, compiled.args).fetch_all(conn.executor()).await?;
+    assert_eq!(rows.len(), 2);
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use bottle_orm::{Database, Model, Op};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+#[orm(primary_key(order_id, product_id))]
+struct OrderItem {
+    product_id: i32,
+    order_id: i32,
+    quantity: i32,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Membership {
+    #[orm(primary_key)]
+    team_id: i32,
+    #[orm(primary_key)]
+    user_id: i32,
+    role: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+#[orm(primary_key(owner_id, r#type), index(columns(r#type, r#ref desc)))]
+struct Tag {
+    owner_id: i32,
+    r#type: String,
+    r#ref: i32,
+}
+
+#[test]
+fn test_composite_primary_key_metadata() {
+    // Struct-level attribute keeps its declared order
+    assert_eq!(OrderItem::primary_keys(), vec!["order_id", "product_id"]);
+    assert!(OrderItem::columns().iter().filter(|c| c.is_primary_key).count() == 2);
+
+    // Multiple field-level keys fall back to declaration order
+    assert_eq!(Membership::primary_keys(), vec!["team_id", "user_id"]);
+}
+
+#[test]
+fn test_raw_identifier_names_match_columns() {
+    let columns: Vec<&str> = Tag::columns().iter().map(|c| c.name).collect();
+    assert_eq!(columns, vec!["owner_id", "type", "ref"]);
+    assert_eq!(Tag::primary_keys(), vec!["owner_id", "type"]);
+
+    let index = &Tag::indexes()[0];
+    assert_eq!(index.name, "idx_tag_type_ref");
+    let index_columns: Vec<&str> = index.columns.iter().map(|c| c.name).collect();
+    assert_eq!(index_columns, vec!["type", "ref"]);
+
+    let tag = Tag { owner_id: 1, r#type: "label".to_string(), r#ref: 3 };
+    assert_eq!(tag.to_map().get("type").map(String::as_str), Some("label"));
+}
+
+#[tokio::test]
+async fn test_raw_identifier_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Tag>().run().await?;
+
+    let tag = Tag { owner_id: 1, r#type: "label".to_string(), r#ref: 3 };
+    db.model::<Tag>().insert(&tag).await?;
+
+    let found: Tag = db.model::<Tag>().filter("type", Op::Eq, "label".to_string()).first().await?;
+    assert_eq!(found, tag);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_composite_primary_key_table() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<OrderItem>().register::<Membership>().run().await?;
+
+    let ddl: String =
+        db.raw("SELECT sql FROM sqlite_master WHERE type = 'table' AND name = 'order_item'").fetch_scalar().await?;
+    assert!(ddl.contains("PRIMARY KEY (\"order_id\", \"product_id\")"), "{}", ddl);
+
+    for (order_id, product_id) in [(2, 1), (1, 2), (1, 1)] {
+        db.model::<OrderItem>().insert(&OrderItem { order_id, product_id, quantity: 1 }).await?;
+    }
+
+    // Same key pair twice violates the constraint
+    let duplicate = db.model::<OrderItem>().insert(&OrderItem { order_id: 1, product_id: 1, quantity: 5 }).await;
+    assert!(duplicate.is_err());
+
+    // Default ordering of first() uses every key column
+    let first: OrderItem = db.model::<OrderItem>().first().await?;
+    assert_eq!((first.order_id, first.product_id), (1, 1));
+
+    db.model::<Membership>().insert(&Membership { team_id: 1, user_id: 7, role: "owner".to_string() }).await?;
+    db.model::<Membership>().insert(&Membership { team_id: 2, user_id: 7, role: "member".to_string() }).await?;
+    assert_eq!(db.model::<Membership>().count().await?, 2);
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use bottle_orm::{
+    Database, Model, Op,
+    database::Drivers,
+    dialect::{SqlDialect, SqliteDialect},
+    raw,
+};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Category {
+    #[orm(primary_key)]
+    id: i32,
+    parent_id: Option<i32>,
+    name: String,
+}
+
+/// SQLite dialect with PostgreSQL-style numbered placeholders (`$1`, `$2`, ...).
+#[derive(Debug)]
+struct NumberedDialect;
+
+impl SqlDialect for NumberedDialect {
+    fn driver(&self) -> Drivers {
+        Drivers::SQLite
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        format!("${}", index)
+    }
+
+    fn quote_ident(&self, ident: &str) -> String {
+        SqliteDialect.quote_ident(ident)
+    }
+
+    fn type_name(&self, sql_type: &str, keyed: bool) -> String {
+        SqliteDialect.type_name(sql_type, keyed)
+    }
+
+    fn limit_offset(&self, limit: Option<&str>, offset: Option<&str>) -> String {
+        SqliteDialect.limit_offset(limit, offset)
+    }
+
+    fn supports_returning(&self) -> bool {
+        true
+    }
+
+    fn upsert(&self, conflict_columns: &[&str], update_columns: &[&str]) -> String {
+        SqliteDialect.upsert(conflict_columns, update_columns)
+    }
+
+    fn now(&self) -> &'static str {
+        SqliteDialect.now()
+    }
+}
+
+async fn setup(db: &Database) -> Result<(), Box<dyn std::error::Error>> {
+    db.migrator().register::<Category>().run().await?;
+
+    let tree = [
+        (1, None, "books"),
+        (2, Some(1), "fiction"),
+        (3, Some(2), "fantasy"),
+        (4, None, "music"),
+        (5, Some(4), "jazz"),
+    ];
+    for (id, parent_id, name) in tree {
+        db.model::<Category>().insert(&Category { id, parent_id, name: name.to_string() }).await?;
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_recursive_cte_walks_tree() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    setup(&db).await?;
+
+    let subtree: Vec<Category> = db
+        .model::<Category>()
+        .with_recursive(
+            "tree",
+            db.model::<Category>().filter("id", Op::Eq, 1),
+            db.model::<Category>().select(raw("category.*")).join("tree", "tree.id = category.parent_id"),
+        )
+        .join("tree", "tree.id = category.id")
+        .order("category.id")
+        .scan()
+        .await?;
+    assert_eq!(subtree.iter().map(|c| c.id).collect::<Vec<_>>(), vec![1, 2, 3]);
+
+    let sql = db
+        .model::<Category>()
+        .with_recursive(
+            "tree",
+            db.model::<Category>().filter("id", Op::Eq, 1),
+            db.model::<Category>().select(raw("category.*")).join("tree", "tree.id = category.parent_id"),
+        )
+        .to_sql();
+    assert!(
+        sql.starts_with("WITH RECURSIVE \"tree\" AS (SELECT * FROM \"category\" WHERE 1=1 AND \"id\" = ? UNION ALL")
+    );
+
+    // Invalid CTE names are rejected when the query runs
+    let invalid = db.model::<Category>().with_cte("tree; --", db.model::<Category>()).scan::<Category>().await;
+    assert!(invalid.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cte_placeholders_are_renumbered() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).dialect(NumberedDialect).connect("sqlite::memory:").await?;
+    setup(&db).await?;
+
+    let query = db
+        .model::<Category>()
+        .with_cte("roots", db.model::<Category>().select("id").filter("name", Op::Eq, "music".to_string()))
+        .with_cte("named", db.model::<Category>().select("parent_id").filter("name", Op::Eq, "jazz".to_string()))
+        .join("roots", "roots.id = category.id")
+        .join("named", "named.parent_id = category.id")
+        .filter("category.id", Op::Gt, 0);
+
+    let (sql, params) = query.to_sql_with_params();
+    assert!(sql.contains("\"name\" = $1)"));
+    assert!(sql.contains("\"name\" = $2)"));
+    assert!(sql.ends_with("\"category\".\"id\" > $3"));
+    assert_eq!(params, vec!["'music'", "'jazz'", "0"]);
+
+    let found: Vec<Category> = query.scan().await?;
+    assert_eq!(found.iter().map(|c| c.id).collect::<Vec<_>>(), vec![4]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_question_mark_operator_is_not_a_numbered_placeholder() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).dialect(NumberedDialect).connect("sqlite::memory:").await?;
+
+    // `?` is an operator on dialects with numbered placeholders (e.g. jsonb key tests)
+    let query = db
+        .model::<Category>()
+        .with_cte("roots", db.model::<Category>().select("id").filter("name", Op::Eq, "music".to_string()))
+        .with_cte("flags", db.model::<Category>().select(raw("id, name ? 'jazz' AS flag")).filter("id", Op::Gt, 1))
+        .join("roots", "roots.id = category.id")
+        .join("flags", "flags.id = category.id");
+
+    let (sql, params) = query.to_sql_with_params();
+    assert!(sql.contains("SELECT id, name ? 'jazz' AS flag FROM"), "{}", sql);
+    assert!(sql.contains("\"name\" = $1)"), "{}", sql);
+    assert!(sql.contains("\"id\" > $2)"), "{}", sql);
+    assert_eq!(params, vec!["'music'", "1"]);
+
+    let interpolated = query.interpolated_sql();
+    assert!(interpolated.contains("name ? 'jazz' AS flag"), "{}", interpolated);
+    assert!(interpolated.contains("\"id\" > 1)"), "{}", interpolated);
+
+    assert_eq!(NumberedDialect.interpolate("data ? 'a' AND id = $1", &["7".to_string()]), "data ? 'a' AND id = 7");
+
+    Ok(())
+}
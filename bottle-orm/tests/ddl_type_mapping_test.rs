@@ -0,0 +1,44 @@
+use bottle_orm::{Database, Model};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Device {
+    #[orm(primary_key)]
+    id: Uuid,
+    #[orm(sql_type(postgres = "CITEXT", sqlite = "TEXT COLLATE NOCASE"))]
+    label: String,
+    weight: f64,
+}
+
+#[test]
+fn test_sql_type_overrides_metadata() {
+    let columns = Device::columns();
+    let label = columns.iter().find(|c| c.name == "label").unwrap();
+
+    // The logical type is kept for value binding
+    assert_eq!(label.sql_type, "TEXT");
+    assert_eq!(label.postgres_type, Some("CITEXT"));
+    assert_eq!(label.mysql_type, None);
+    assert_eq!(label.sqlite_type, Some("TEXT COLLATE NOCASE"));
+}
+
+#[tokio::test]
+async fn test_ddl_types_resolved_for_sqlite() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Device>().run().await?;
+
+    let ddl: String =
+        db.raw("SELECT sql FROM sqlite_master WHERE type = 'table' AND name = 'device'").fetch_scalar().await?;
+    assert!(ddl.contains("\"id\" TEXT PRIMARY KEY"), "{}", ddl);
+    assert!(ddl.contains("\"label\" TEXT COLLATE NOCASE NOT NULL"), "{}", ddl);
+    assert!(ddl.contains("\"weight\" REAL NOT NULL"), "{}", ddl);
+
+    let id = Uuid::new_v4();
+    db.model::<Device>().insert(&Device { id, label: "Sensor".to_string(), weight: 1.5 }).await?;
+
+    // The override is applied: NOCASE collation matches regardless of case
+    let found: Device = db.model::<Device>().equals("label", "sensor".to_string()).first().await?;
+    assert_eq!(found.id, id);
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use bottle_orm::{
+    dialect::{MySqlDialect, PostgresDialect, SqlDialect, SqliteDialect},
+    Database, Model, Op,
+};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Account {
+    #[orm(primary_key)]
+    id: i32,
+    username: String,
+    #[orm(sensitive)]
+    password_hash: String,
+}
+
+#[tokio::test]
+async fn test_sql_with_params_and_redaction() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+
+    let query = db
+        .model::<Account>()
+        .filter("username", Op::Eq, "o'brien".to_string())
+        .filter("account.password_hash", Op::Eq, "s3cret".to_string())
+        .limit(1);
+
+    let (sql, params) = query.to_sql_with_params();
+    assert_eq!(
+        sql,
+        "SELECT * FROM \"account\" WHERE 1=1 AND \"username\" = ? AND \"account\".\"password_hash\" = ? LIMIT ?"
+    );
+    assert_eq!(params, vec!["'o''brien'", "'[REDACTED]'", "1"]);
+
+    assert_eq!(
+        query.interpolated_sql(),
+        "SELECT * FROM \"account\" WHERE 1=1 AND \"username\" = 'o''brien' \
+         AND \"account\".\"password_hash\" = '[REDACTED]' LIMIT 1"
+    );
+
+    // Redaction only affects debug output; the query still matches on the real value
+    db.migrator().register::<Account>().run().await?;
+    let account = Account { id: 1, username: "o'brien".to_string(), password_hash: "s3cret".to_string() };
+    db.model::<Account>().insert(&account).await?;
+    let found: Account = query.first().await?;
+    assert_eq!(found, account);
+
+    Ok(())
+}
+
+#[test]
+fn test_dialect_literals() {
+    assert_eq!(MySqlDialect.quote_literal("a\\b'c"), "'a\\\\b''c'");
+    assert_eq!(SqliteDialect.quote_literal("a\\b'c"), "'a\\b''c'");
+    assert_eq!(PostgresDialect.blob_literal(&[0xde, 0xad]), "'\\xdead'::bytea");
+    assert_eq!(SqliteDialect.blob_literal(&[0xde, 0xad]), "X'DEAD'");
+
+    let literals = vec!["'x'".to_string(), "2".to_string()];
+    assert_eq!(PostgresDialect.interpolate("a = $2 AND b = $1", &literals), "a = 2 AND b = 'x'");
+    // Placeholders inside quoted strings are left alone
+    assert_eq!(SqliteDialect.interpolate("'?' = ? AND \"c?\" = ?", &literals), "'?' = 'x' AND \"c?\" = 2");
+}
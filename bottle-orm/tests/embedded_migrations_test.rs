@@ -0,0 +1,54 @@
+use bottle_orm::{embed_migrations, Database, Model, SqlMigration};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Note {
+    #[orm(primary_key)]
+    id: i32,
+    body: String,
+}
+
+const MIGRATIONS: &[SqlMigration] = embed_migrations!("tests/migrations");
+
+#[test]
+fn test_embedded_migrations_are_parsed() {
+    assert_eq!(MIGRATIONS.len(), 2);
+    assert_eq!(MIGRATIONS[0].version, 1);
+    assert_eq!(MIGRATIONS[0].name, "create_note_stats");
+    assert!(MIGRATIONS[0].down.default.is_some());
+    assert_eq!(MIGRATIONS[1].name, "seed_notes");
+    assert!(MIGRATIONS[1].up.sqlite.is_some());
+    assert!(MIGRATIONS[1].up.postgres.is_none());
+}
+
+#[tokio::test]
+async fn test_sql_migrations_run_once_and_rollback() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+
+    db.migrator().register::<Note>().register_sql(MIGRATIONS).run().await?;
+
+    // Driver-specific variant wins over the generic script
+    let note: Note = db.model::<Note>().first().await?;
+    assert_eq!(note.body, "sqlite");
+
+    let total: i64 = db.raw("SELECT \"total\" FROM \"note_stats\"").fetch_scalar().await?;
+    assert_eq!(total, 1);
+
+    // Running again must not re-apply the seed (would violate the primary key)
+    db.migrator().register::<Note>().register_sql(MIGRATIONS).run().await?;
+    let applied: i64 = db.raw("SELECT COUNT(*) FROM \"_bottle_migrations\"").fetch_scalar().await?;
+    assert_eq!(applied, 2);
+
+    // Revert the seed only
+    db.migrator().register_sql(MIGRATIONS).rollback(1).await?;
+    let count: i64 = db.model::<Note>().count().await?;
+    assert_eq!(count, 0);
+    let applied: i64 = db.raw("SELECT COUNT(*) FROM \"_bottle_migrations\"").fetch_scalar().await?;
+    assert_eq!(applied, 1);
+
+    // Re-applying picks up the reverted version
+    db.migrator().register_sql(MIGRATIONS).run().await?;
+    let count: i64 = db.model::<Note>().count().await?;
+    assert_eq!(count, 1);
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use bottle_orm::{Database, Error, FromAnyRow, Model, Op};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Tag {
+    #[orm(primary_key, auto_increment)]
+    id: i64,
+    #[orm(unique)]
+    name: String,
+    color: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Setting {
+    #[orm(primary_key, auto_increment)]
+    id: i64,
+    owner: String,
+    key: String,
+    value: String,
+}
+
+#[derive(Debug, FromAnyRow)]
+struct ByName {
+    name: String,
+}
+
+#[derive(Debug, FromAnyRow)]
+struct ByOwnerKey {
+    owner: String,
+    key: String,
+}
+
+#[derive(Debug, FromAnyRow)]
+struct ByNickname {
+    nickname: String,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Tag>().register::<Setting>().run().await?;
+    Ok(db)
+}
+
+fn tag(name: &str, color: &str) -> Tag {
+    Tag { id: 0, name: name.to_string(), color: color.to_string() }
+}
+
+#[tokio::test]
+async fn test_find_or_create_with_unique_key() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+    let rust = ByName { name: "rust".to_string() };
+
+    // The filter values win over the defaults
+    let (created, was_created) = db.model::<Tag>().find_or_create(&rust, &tag("ignored", "orange")).await?;
+    assert!(was_created);
+    assert_eq!((created.id, created.name.as_str(), created.color.as_str()), (1, "rust", "orange"));
+
+    let (found, was_created) = db.model::<Tag>().find_or_create(&rust, &tag("rust", "blue")).await?;
+    assert!(!was_created);
+    assert_eq!(found, created);
+
+    // Existing rows are overwritten, new ones inserted
+    let (updated, was_created) = db.model::<Tag>().update_or_create(&rust, &tag("rust", "blue")).await?;
+    assert!(!was_created);
+    assert_eq!((updated.id, updated.color.as_str()), (1, "blue"));
+
+    let go = ByName { name: "go".to_string() };
+    let (inserted, was_created) = db.model::<Tag>().update_or_create(&go, &tag("go", "cyan")).await?;
+    assert!(was_created);
+    assert_eq!((inserted.id, inserted.color.as_str()), (2, "cyan"));
+    assert_eq!(db.model::<Tag>().count().await?, 2);
+
+    let unknown = db.model::<Tag>().find_or_create(&ByNickname { nickname: "x".to_string() }, &tag("x", "red")).await;
+    assert!(matches!(unknown, Err(Error::InvalidArgument(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_find_or_create_without_unique_key_in_transaction() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+    let theme = ByOwnerKey { owner: "ada".to_string(), key: "theme".to_string() };
+    let defaults = Setting { id: 0, owner: String::new(), key: String::new(), value: "light".to_string() };
+
+    let mut tx = db.begin().await?;
+    let (created, was_created) = tx.model::<Setting>().find_or_create(&theme, &defaults).await?;
+    assert!(was_created);
+    assert_eq!((created.owner.as_str(), created.key.as_str(), created.value.as_str()), ("ada", "theme", "light"));
+
+    let (found, was_created) = tx.model::<Setting>().find_or_create(&theme, &defaults).await?;
+    assert!(!was_created);
+    assert_eq!(found.id, created.id);
+    tx.commit().await?;
+
+    // A unique violation inside the savepoint leaves the transaction usable
+    db.model::<Tag>().insert(&tag("rust", "orange")).await?;
+    let mut tx = db.begin().await?;
+    let conflict =
+        tx.model::<Tag>().filter("color", Op::Eq, "orange".to_string()).first_or_insert(&tag("sql", "orange")).await?;
+    assert_eq!((conflict.0.name.as_str(), conflict.1), ("rust", false));
+
+    let duplicate =
+        tx.model::<Tag>().filter("color", Op::Eq, "green".to_string()).first_or_insert(&tag("rust", "green")).await;
+    assert!(matches!(duplicate, Err(Error::UniqueViolation { .. })), "got {:?}", duplicate);
+
+    let (inserted, was_created) =
+        tx.model::<Tag>().filter("color", Op::Eq, "green".to_string()).first_or_insert(&tag("go", "green")).await?;
+    assert!(was_created);
+    assert_eq!(inserted.name, "go");
+    tx.commit().await?;
+
+    assert_eq!(db.model::<Tag>().count().await?, 2);
+
+    Ok(())
+}
+
+#[derive(Debug, FromAnyRow)]
+struct ByNameColor {
+    name: String,
+    color: String,
+}
+
+#[tokio::test]
+async fn test_find_or_create_unique_key_coverage_in_strict_mode() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+    let theme = ByOwnerKey { owner: "ada".to_string(), key: "theme".to_string() };
+    let defaults = Setting { id: 0, owner: String::new(), key: String::new(), value: "light".to_string() };
+
+    // Without a unique key concurrent callers could insert duplicates, so strict mode refuses
+    let rejected = db.model::<Setting>().strict().find_or_create(&theme, &defaults).await;
+    assert!(matches!(rejected, Err(Error::InvalidArgument(_))), "got {:?}", rejected);
+    let rejected = db.model::<Setting>().strict().update_or_create(&theme, &defaults).await;
+    assert!(matches!(rejected, Err(Error::InvalidArgument(_))), "got {:?}", rejected);
+    assert_eq!(db.model::<Setting>().count().await?, 0);
+
+    // Filters that include a unique column cover its key
+    let rust = ByNameColor { name: "rust".to_string(), color: "orange".to_string() };
+    let (created, was_created) = db.model::<Tag>().strict().find_or_create(&rust, &tag("", "")).await?;
+    assert!(was_created);
+    assert_eq!((created.name.as_str(), created.color.as_str()), ("rust", "orange"));
+
+    let (found, was_created) = db.model::<Tag>().strict().find_or_create(&rust, &tag("", "")).await?;
+    assert!(!was_created);
+    assert_eq!(found, created);
+
+    Ok(())
+}
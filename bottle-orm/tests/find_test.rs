@@ -0,0 +1,74 @@
+use bottle_orm::{Database, Error, Model, Op};
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Article {
+    #[orm(primary_key)]
+    id: i32,
+    title: String,
+    #[orm(soft_delete)]
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Tagging {
+    #[orm(primary_key)]
+    article_id: i32,
+    #[orm(primary_key)]
+    tag_id: i32,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Article>().register::<Tagging>().run().await?;
+
+    for (id, title) in [(1, "Rust"), (2, "SQL"), (3, "Draft")] {
+        db.model::<Article>().insert(&Article { id, title: title.to_string(), deleted_at: None }).await?;
+    }
+    db.model::<Article>().filter("id", Op::Eq, 3).delete().await?;
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_find_by_primary_key() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let article = db.model::<Article>().find(2).await?;
+    assert_eq!(article.map(|a| a.title), Some("SQL".to_string()));
+    assert_eq!(db.model::<Article>().find(42).await?, None);
+
+    // Soft-deleted rows are hidden unless requested
+    assert_eq!(db.model::<Article>().find(3).await?, None);
+    assert!(db.model::<Article>().with_deleted().find(3).await?.is_some());
+
+    let articles = db.model::<Article>().order("id DESC").find_many(vec![1, 2, 3, 42]).await?;
+    assert_eq!(articles.iter().map(|a| a.id).collect::<Vec<_>>(), vec![2, 1]);
+    assert!(db.model::<Article>().find_many(Vec::<i32>::new()).await?.is_empty());
+
+    assert_eq!(db.model::<Article>().find_or_fail(1).await?.title, "Rust");
+    assert!(matches!(db.model::<Article>().find_or_fail(3).await, Err(Error::NotFound)));
+
+    // Composite keys cannot be looked up by a single value
+    let composite = db.model::<Tagging>().find(1).await;
+    assert!(matches!(composite, Err(Error::InvalidArgument(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_first_optional_and_exists() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let found: Option<Article> =
+        db.model::<Article>().filter("title", Op::Eq, "Rust".to_string()).first_optional().await?;
+    assert_eq!(found.map(|a| a.id), Some(1));
+    let missing: Option<Article> =
+        db.model::<Article>().filter("title", Op::Eq, "Go".to_string()).first_optional().await?;
+    assert!(missing.is_none());
+
+    assert!(db.model::<Article>().filter("title", Op::Like, "R%".to_string()).exists().await?);
+    assert!(!db.model::<Article>().filter("title", Op::Eq, "Draft".to_string()).exists().await?);
+    assert!(db.model::<Article>().with_deleted().filter("title", Op::Eq, "Draft".to_string()).exists().await?);
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use bottle_orm::{database::Drivers, Database, Model};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Order {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(index)]
+    group: String,
+}
+
+#[test]
+fn test_quote_ident_per_driver() {
+    assert_eq!(Drivers::Postgres.quote_ident("order"), "\"order\"");
+    assert_eq!(Drivers::SQLite.quote_ident("order"), "\"order\"");
+    assert_eq!(Drivers::MySQL.quote_ident("order"), "`order`");
+
+    // Embedded quote characters are doubled
+    assert_eq!(Drivers::Postgres.quote_ident("we\"ird"), "\"we\"\"ird\"");
+    assert_eq!(Drivers::MySQL.quote_ident("we`ird"), "`we``ird`");
+
+    assert_eq!(Drivers::MySQL.quote_column("order.group"), "`order`.`group`");
+}
+
+#[tokio::test]
+async fn test_reserved_word_identifiers() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Order>().run().await?;
+
+    db.model::<Order>().insert(&Order { id: 1, group: "a".to_string() }).await?;
+    db.model::<Order>().insert(&Order { id: 2, group: "b".to_string() }).await?;
+
+    let found: Order = db.model::<Order>().equals("group", "b".to_string()).first().await?;
+    assert_eq!(found.id, 2);
+
+    db.model::<Order>().equals("order.id", 1).update("group", "c").await?;
+    let renamed: Vec<Order> = db.model::<Order>().equals("group", "c".to_string()).scan().await?;
+    assert_eq!(renamed.len(), 1);
+
+    assert_eq!(db.model::<Order>().equals("id", 2).hard_delete().await?, 1);
+    assert_eq!(db.model::<Order>().count().await?, 1);
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use std::time::Duration;
+
+use bottle_orm::{
+    Database, Error, Model,
+    migration::{SqlMigration, SqlScript},
+};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Ticket {
+    #[orm(primary_key)]
+    id: i32,
+    title: String,
+}
+
+#[tokio::test]
+async fn test_migration_waits_for_lock_and_times_out() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+
+    // Simulate another instance holding the lock
+    db.raw("CREATE TABLE \"_bottle_migrations_lock\" (\"id\" INTEGER PRIMARY KEY, \"locked_at\" TEXT NOT NULL)")
+        .execute()
+        .await?;
+    db.raw("INSERT INTO \"_bottle_migrations_lock\" (\"id\", \"locked_at\") VALUES (1, 'now')").execute().await?;
+
+    let result = db.migrator().lock_timeout(Duration::from_millis(200)).register::<Ticket>().run().await;
+    match result {
+        Err(Error::Timeout(sqlx::Error::Io(e))) => assert_eq!(e.kind(), std::io::ErrorKind::TimedOut),
+        other => panic!("expected lock timeout, got {:?}", other.map(|_| ())),
+    }
+
+    // Once the other instance releases the lock, migration proceeds and releases it again
+    db.raw("DELETE FROM \"_bottle_migrations_lock\"").execute().await?;
+    db.migrator().lock_timeout(Duration::from_millis(200)).register::<Ticket>().run().await?;
+
+    let held: i64 = db.raw("SELECT COUNT(*) FROM \"_bottle_migrations_lock\"").fetch_scalar().await?;
+    assert_eq!(held, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stale_lock_is_taken_over() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+
+    // A process that died mid-migration left its lock row behind
+    db.raw("CREATE TABLE \"_bottle_migrations_lock\" (\"id\" INTEGER PRIMARY KEY, \"locked_at\" TEXT NOT NULL)")
+        .execute()
+        .await?;
+    db.raw("INSERT INTO \"_bottle_migrations_lock\" (\"id\", \"locked_at\") VALUES (1, '2000-01-01T00:00:00.000000Z')")
+        .execute()
+        .await?;
+
+    db.migrator()
+        .lock_timeout(Duration::from_millis(200))
+        .stale_lock_after(Duration::from_secs(60))
+        .register::<Ticket>()
+        .run()
+        .await?;
+
+    let held: i64 = db.raw("SELECT COUNT(*) FROM \"_bottle_migrations_lock\"").fetch_scalar().await?;
+    assert_eq!(held, 0);
+    assert_eq!(db.model::<Ticket>().count().await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lock_errors_other_than_conflicts_are_not_retried() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+
+    // A lock table without the locked_at column fails every lock attempt
+    db.raw("CREATE TABLE \"_bottle_migrations_lock\" (\"id\" INTEGER PRIMARY KEY)").execute().await?;
+
+    let started = std::time::Instant::now();
+    let result = db.migrator().lock_timeout(Duration::from_secs(30)).register::<Ticket>().run().await;
+    assert!(matches!(result, Err(Error::DatabaseError(_))), "got {:?}", result.map(|_| ()));
+    assert!(started.elapsed() < Duration::from_secs(5));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_concurrent_migrators_are_serialized() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+
+    let (first, second) = tokio::join!(
+        db.migrator().register::<Ticket>().run(),
+        db.migrator().register::<Ticket>().run()
+    );
+    first?;
+    second?;
+
+    db.model::<Ticket>().insert(&Ticket { id: 1, title: "ok".to_string() }).await?;
+    assert_eq!(db.model::<Ticket>().count().await?, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_without_lock_skips_lock_table() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+
+    db.migrator().without_lock().register::<Ticket>().run().await?;
+
+    let tables: i64 = db
+        .raw("SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = '_bottle_migrations_lock'")
+        .fetch_scalar()
+        .await?;
+    assert_eq!(tables, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_failed_migration_reports_its_error_and_releases_lock() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+
+    let broken = SqlMigration {
+        version: 1,
+        name: "broken",
+        up: SqlScript { default: Some("SELECT * FROM \"missing_table\""), ..SqlScript::default() },
+        down: SqlScript::default(),
+    };
+
+    let result = db.migrator().register::<Ticket>().register_sql(&[broken]).run().await;
+    match result {
+        Err(Error::DatabaseError(e)) => assert!(e.to_string().contains("missing_table"), "{}", e),
+        other => panic!("expected the migration error, got {:?}", other.map(|_| ())),
+    }
+
+    let held: i64 = db.raw("SELECT COUNT(*) FROM \"_bottle_migrations_lock\"").fetch_scalar().await?;
+    assert_eq!(held, 0);
+
+    Ok(())
+}
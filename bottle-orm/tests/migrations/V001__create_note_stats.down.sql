@@ -0,0 +1,2 @@
+DROP INDEX "idx_note_body_lookup";
+DROP VIEW "note_stats";
@@ -0,0 +1,2 @@
+CREATE VIEW "note_stats" AS SELECT COUNT(*) AS "total" FROM "note";
+CREATE INDEX "idx_note_body_lookup" ON "note" ("body");
@@ -0,0 +1 @@
+DELETE FROM "note" WHERE "id" = 1;
@@ -0,0 +1,56 @@
+use bottle_orm::{
+    Database, Direction, Model, Nulls,
+    dialect::{MySqlDialect, PostgresDialect, SqlDialect},
+};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Player {
+    #[orm(primary_key)]
+    id: i32,
+    score: Option<i32>,
+}
+
+#[tokio::test]
+async fn test_order_by_nulls_placement() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Player>().run().await?;
+
+    for (id, score) in [(1, Some(10)), (2, None), (3, Some(30))] {
+        db.model::<Player>().insert(&Player { id, score }).await?;
+    }
+
+    let sql = db
+        .model::<Player>()
+        .order_by("score", Direction::Desc)
+        .nulls_last()
+        .order_by("player.id", Direction::Asc)
+        .to_sql();
+    assert!(sql.ends_with("ORDER BY \"score\" DESC NULLS LAST, \"player\".\"id\" ASC"));
+
+    let last: Vec<Player> = db.model::<Player>().order_by("score", Direction::Desc).nulls_last().scan().await?;
+    assert_eq!(last.iter().map(|p| p.id).collect::<Vec<_>>(), vec![3, 1, 2]);
+
+    let first: Vec<Player> = db.model::<Player>().order_by("score", Direction::Asc).nulls_first().scan().await?;
+    assert_eq!(first.iter().map(|p| p.id).collect::<Vec<_>>(), vec![2, 1, 3]);
+
+    // Unknown columns are rejected instead of being spliced into SQL
+    let invalid = db.model::<Player>().order_by("score; DROP TABLE player", Direction::Asc).scan::<Player>().await;
+    assert!(invalid.is_err());
+    assert_eq!(db.model::<Player>().count().await?, 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_nulls_emulation_per_dialect() {
+    assert_eq!(PostgresDialect.order_by("\"score\"", Direction::Desc, Some(Nulls::Last)), "\"score\" DESC NULLS LAST");
+    assert_eq!(
+        MySqlDialect.order_by("`score`", Direction::Desc, Some(Nulls::Last)),
+        "`score` IS NULL ASC, `score` DESC"
+    );
+    assert_eq!(
+        MySqlDialect.order_by("`score`", Direction::Asc, Some(Nulls::First)),
+        "`score` IS NULL DESC, `score` ASC"
+    );
+    assert_eq!(MySqlDialect.order_by("`score`", Direction::Asc, None), "`score` ASC");
+}
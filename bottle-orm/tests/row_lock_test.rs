@@ -0,0 +1,107 @@
+use bottle_orm::{
+    Database, LockMode, LockWait, Model, Op,
+    database::Drivers,
+    dialect::{MySqlDialect, PostgresDialect, SqlDialect, SqliteDialect},
+};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Account {
+    #[orm(primary_key)]
+    id: i32,
+    balance: i64,
+}
+
+/// SQLite-compatible dialect that keeps the default (PostgreSQL / MySQL) lock rendering.
+#[derive(Debug)]
+struct ServerLockDialect;
+
+impl SqlDialect for ServerLockDialect {
+    fn driver(&self) -> Drivers {
+        Drivers::SQLite
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        SqliteDialect.placeholder(index)
+    }
+
+    fn quote_ident(&self, ident: &str) -> String {
+        SqliteDialect.quote_ident(ident)
+    }
+
+    fn type_name(&self, sql_type: &str, keyed: bool) -> String {
+        SqliteDialect.type_name(sql_type, keyed)
+    }
+
+    fn limit_offset(&self, limit: Option<&str>, offset: Option<&str>) -> String {
+        SqliteDialect.limit_offset(limit, offset)
+    }
+
+    fn supports_returning(&self) -> bool {
+        true
+    }
+
+    fn upsert(&self, conflict_columns: &[&str], update_columns: &[&str]) -> String {
+        SqliteDialect.upsert(conflict_columns, update_columns)
+    }
+
+    fn now(&self) -> &'static str {
+        SqliteDialect.now()
+    }
+}
+
+#[tokio::test]
+async fn test_lock_for_update_in_transaction() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Account>().run().await?;
+    db.model::<Account>().insert(&Account { id: 1, balance: 100 }).await?;
+
+    let mut tx = db.begin().await?;
+    let account: Account =
+        tx.model::<Account>().filter("id", Op::Eq, 1).lock_for_update().skip_locked().first().await?;
+    tx.model::<Account>().filter("id", Op::Eq, 1).update("balance", account.balance - 40).await?;
+    tx.commit().await?;
+
+    let account: Account = db.model::<Account>().filter("id", Op::Eq, 1).first().await?;
+    assert_eq!(account.balance, 60);
+
+    // Row locks are rejected outside a transaction
+    let pool_lock = db.model::<Account>().lock_for_update().scan::<Account>().await;
+    assert!(pool_lock.is_err());
+
+    // Wait modifiers require a lock
+    let mut tx = db.begin().await?;
+    let no_lock = tx.model::<Account>().nowait().scan::<Account>().await;
+    assert!(no_lock.is_err());
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+#[test]
+fn test_lock_clause_per_dialect() {
+    assert_eq!(PostgresDialect.lock_clause(LockMode::Update, LockWait::Wait), " FOR UPDATE");
+    assert_eq!(PostgresDialect.lock_clause(LockMode::Update, LockWait::SkipLocked), " FOR UPDATE SKIP LOCKED");
+    assert_eq!(MySqlDialect.lock_clause(LockMode::Share, LockWait::NoWait), " FOR SHARE NOWAIT");
+
+    assert_eq!(SqliteDialect.lock_clause(LockMode::Update, LockWait::SkipLocked), "");
+    assert_eq!(SqliteDialect.lock_emulation("\"account\"").as_deref(), Some("DELETE FROM \"account\" WHERE 0"));
+    assert_eq!(PostgresDialect.lock_emulation("\"account\""), None);
+}
+
+#[tokio::test]
+async fn test_lock_clause_follows_limit() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).dialect(ServerLockDialect).connect("sqlite::memory:").await?;
+    let mut tx = db.begin().await?;
+
+    let compiled = tx.model::<Account>().filter("id", Op::Eq, 1).limit(1).lock_for_update().skip_locked().build();
+    assert_eq!(
+        compiled.sql,
+        "SELECT * FROM \"account\" WHERE 1=1 AND \"id\" = ? LIMIT ? FOR UPDATE SKIP LOCKED"
+    );
+
+    let compiled = tx.model::<Account>().lock_for_share().nowait().build();
+    assert_eq!(compiled.sql, "SELECT * FROM \"account\" WHERE 1=1 FOR SHARE NOWAIT");
+
+    tx.rollback().await?;
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use bottle_orm::{Database, Error, Model, Transaction};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Entry {
+    #[orm(primary_key)]
+    id: i32,
+    label: String,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Entry>().run().await?;
+    Ok(db)
+}
+
+fn entry(id: i32) -> Entry {
+    Entry { id, label: format!("entry {}", id) }
+}
+
+#[tokio::test]
+async fn test_savepoint_commit_and_rollback() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let mut tx = db.begin().await?;
+    tx.model::<Entry>().insert(&entry(1)).await?;
+
+    let mut released = tx.savepoint().await?;
+    released.model::<Entry>().insert(&entry(2)).await?;
+    released.commit().await?;
+
+    let mut discarded = tx.begin().await?;
+    discarded.model::<Entry>().insert(&entry(3)).await?;
+    let mut inner = discarded.savepoint().await?;
+    inner.model::<Entry>().insert(&entry(4)).await?;
+    inner.commit().await?;
+    discarded.rollback().await?;
+
+    // Dropping a savepoint handle rolls it back as well
+    {
+        let mut dropped = tx.savepoint().await?;
+        dropped.model::<Entry>().insert(&entry(5)).await?;
+    }
+
+    tx.model::<Entry>().insert(&entry(6)).await?;
+    tx.commit().await?;
+
+    let ids: Vec<i32> = db.model::<Entry>().select("id").order("id").scan().await?;
+    assert_eq!(ids, vec![1, 2, 6]);
+
+    Ok(())
+}
+
+/// A service function that composes inside any caller's transaction.
+async fn create_pair(tx: &mut Transaction<'_>, first: i32, fail: bool) -> Result<(), Error> {
+    tx.transaction(|sp| {
+        Box::pin(async move {
+            sp.model::<Entry>().insert(&entry(first)).await?;
+            sp.model::<Entry>().insert(&entry(first + 1)).await?;
+            if fail {
+                return Err(Error::invalid_data("pair rejected"));
+            }
+            Ok(())
+        })
+    })
+    .await
+}
+
+#[tokio::test]
+async fn test_nested_closure_transactions() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    db.transaction(|tx| {
+        Box::pin(async move {
+            create_pair(tx, 10, false).await?;
+            assert!(create_pair(tx, 20, true).await.is_err());
+            create_pair(tx, 30, false).await
+        })
+    })
+    .await?;
+
+    let ids: Vec<i32> = db.model::<Entry>().select("id").order("id").scan().await?;
+    assert_eq!(ids, vec![10, 11, 30, 31]);
+
+    Ok(())
+}
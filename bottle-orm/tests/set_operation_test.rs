@@ -0,0 +1,114 @@
+use bottle_orm::{Database, Error, FromAnyRow, Model, Op, Pagination, raw};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Post {
+    #[orm(primary_key)]
+    id: i32,
+    body: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Comment {
+    #[orm(primary_key)]
+    id: i32,
+    post_id: i32,
+    body: String,
+}
+
+#[derive(Debug, FromAnyRow)]
+struct Activity {
+    id: i32,
+    body: String,
+    kind: String,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Post>().register::<Comment>().run().await?;
+
+    for (id, body) in [(1, "hello"), (3, "release notes"), (5, "draft")] {
+        db.model::<Post>().insert(&Post { id, body: body.to_string() }).await?;
+    }
+    for (id, post_id, body) in [(2, 1, "hello"), (4, 3, "nice"), (6, 3, "thanks")] {
+        db.model::<Comment>().insert(&Comment { id, post_id, body: body.to_string() }).await?;
+    }
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_union_all_feed_with_outer_order_and_limit() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let feed = || {
+        let comments = db
+            .model::<Comment>()
+            .select("id")
+            .select("body")
+            .select_expr(raw("'comment'"), "kind")
+            .filter("post_id", Op::Eq, 3);
+        db.model::<Post>()
+            .select("id")
+            .select("body")
+            .select_expr(raw("'post'"), "kind")
+            .filter("body", Op::Ne, "draft".to_string())
+            .union_all(comments)
+    };
+
+    let activity: Vec<Activity> = feed().order("id DESC").limit(3).scan().await?;
+    let summary: Vec<(i32, &str)> = activity.iter().map(|a| (a.id, a.kind.as_str())).collect();
+    assert_eq!(summary, vec![(6, "comment"), (4, "comment"), (3, "post")]);
+    assert_eq!(activity[2].body, "release notes");
+
+    let page = Pagination::new(0, 2).paginate::<_, _, Activity>(feed().order("id")).await?;
+    assert_eq!(page.total, 4);
+    assert_eq!(page.data.iter().map(|a| a.id).collect::<Vec<_>>(), vec![1, 3]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_intersect_and_except() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let shared: Vec<String> =
+        db.model::<Post>().select("body").intersect(db.model::<Comment>().select("body")).scan().await?;
+    assert_eq!(shared, vec!["hello".to_string()]);
+
+    let only_posts: Vec<String> =
+        db.model::<Post>().select("body").except(db.model::<Comment>().select("body")).order("body").scan().await?;
+    assert_eq!(only_posts, vec!["draft".to_string(), "release notes".to_string()]);
+
+    // ORDER BY / LIMIT belong to the outer query
+    let invalid =
+        db.model::<Post>().select("body").union(db.model::<Comment>().select("body").limit(1)).scan::<String>().await;
+    assert!(invalid.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_aggregates_over_combined_rows() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let combined = || {
+        db.model::<Post>()
+            .select("id")
+            .select("body")
+            .union_all(db.model::<Comment>().select("id").select("body").filter("post_id", Op::Eq, 3))
+    };
+
+    assert_eq!(combined().count().await?, 5);
+    assert_eq!(combined().sum::<i64>("id").await?, 1 + 3 + 5 + 4 + 6);
+    assert_eq!(combined().max::<i32>("id").await?, 6);
+    assert_eq!(combined().min::<i32>("post.id").await?, 1);
+    assert_eq!(combined().avg::<f64>("id").await?, 19.0 / 5.0);
+
+    // UNION drops the duplicate "hello" before counting
+    let bodies = db.model::<Post>().select("body").union(db.model::<Comment>().select("body"));
+    assert_eq!(bodies.count().await?, 5);
+
+    let per_branch = combined().select("count(*)").scalar::<i64>().await;
+    assert!(matches!(per_branch, Err(Error::InvalidArgument(_))));
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use bottle_orm::{
+    database::Drivers,
+    dialect::{MySqlDialect, PostgresDialect, SqlDialect, SqliteDialect},
+    Database, Error, Model,
+};
+use chrono::NaiveDate;
+use sqlx::{Arguments, any::AnyArguments};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Note {
+    #[orm(primary_key)]
+    id: i32,
+    body: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Holiday {
+    #[orm(primary_key)]
+    id: i32,
+    day: NaiveDate,
+}
+
+/// SQLite dialect that stores dates as compact `YYYYMMDD` text.
+#[derive(Debug)]
+struct CompactDateDialect;
+
+impl SqlDialect for CompactDateDialect {
+    fn driver(&self) -> Drivers {
+        Drivers::SQLite
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        SqliteDialect.placeholder(index)
+    }
+
+    fn quote_ident(&self, ident: &str) -> String {
+        SqliteDialect.quote_ident(ident)
+    }
+
+    fn type_name(&self, sql_type: &str, keyed: bool) -> String {
+        SqliteDialect.type_name(sql_type, keyed)
+    }
+
+    fn bind_value(&self, args: &mut AnyArguments<'_>, value: &str, sql_type: &str) -> Result<(), Error> {
+        match sql_type {
+            "DATE" => {
+                let _ = args.add(value.replace('-', ""));
+                Ok(())
+            }
+            _ => SqliteDialect.bind_value(args, value, sql_type),
+        }
+    }
+
+    fn select_temporal(&self, column: &str, alias: &str) -> String {
+        format!("substr({0}, 1, 4) || '-' || substr({0}, 5, 2) || '-' || substr({0}, 7, 2) AS {1}", column, alias)
+    }
+
+    fn limit_offset(&self, limit: Option<&str>, offset: Option<&str>) -> String {
+        SqliteDialect.limit_offset(limit, offset)
+    }
+
+    fn supports_returning(&self) -> bool {
+        true
+    }
+
+    fn upsert(&self, conflict_columns: &[&str], update_columns: &[&str]) -> String {
+        SqliteDialect.upsert(conflict_columns, update_columns)
+    }
+
+    fn now(&self) -> &'static str {
+        SqliteDialect.now()
+    }
+}
+
+/// SQLite dialect that quotes identifiers with square brackets.
+#[derive(Debug)]
+struct BracketDialect;
+
+impl SqlDialect for BracketDialect {
+    fn driver(&self) -> Drivers {
+        Drivers::SQLite
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        SqliteDialect.placeholder(index)
+    }
+
+    fn quote_ident(&self, ident: &str) -> String {
+        format!("[{}]", ident)
+    }
+
+    fn type_name(&self, sql_type: &str, keyed: bool) -> String {
+        SqliteDialect.type_name(sql_type, keyed)
+    }
+
+    fn limit_offset(&self, limit: Option<&str>, offset: Option<&str>) -> String {
+        SqliteDialect.limit_offset(limit, offset)
+    }
+
+    fn supports_returning(&self) -> bool {
+        true
+    }
+
+    fn upsert(&self, conflict_columns: &[&str], update_columns: &[&str]) -> String {
+        SqliteDialect.upsert(conflict_columns, update_columns)
+    }
+
+    fn now(&self) -> &'static str {
+        SqliteDialect.now()
+    }
+}
+
+#[test]
+fn test_builtin_dialects() {
+    assert_eq!(PostgresDialect.placeholder(3), "$3");
+    assert_eq!(MySqlDialect.placeholder(3), "?");
+    assert_eq!(PostgresDialect.cast_placeholder("$1".to_string(), "UUID"), "$1::UUID");
+    assert_eq!(SqliteDialect.cast_placeholder("?".to_string(), "UUID"), "?");
+
+    assert_eq!(PostgresDialect.limit_offset(None, Some("$1")), " OFFSET $1");
+    assert_eq!(SqliteDialect.limit_offset(None, Some("?")), " LIMIT -1 OFFSET ?");
+    assert_eq!(MySqlDialect.limit_offset(Some("?"), Some("?")), " LIMIT ? OFFSET ?");
+
+    assert_eq!(PostgresDialect.returning(&["id"]).as_deref(), Some(" RETURNING \"id\""));
+    assert_eq!(MySqlDialect.returning(&["id"]), None);
+
+    assert_eq!(
+        SqliteDialect.upsert(&["id"], &["body"]),
+        " ON CONFLICT (\"id\") DO UPDATE SET \"body\" = EXCLUDED.\"body\""
+    );
+    assert_eq!(MySqlDialect.upsert(&["id"], &["body"]), " ON DUPLICATE KEY UPDATE `body` = VALUES(`body`)");
+
+    assert_eq!(Drivers::MySQL.dialect().type_name("UUID", false), "CHAR(36)");
+
+    assert_eq!(PostgresDialect.select_temporal("\"at\"", "\"at\""), "to_json(\"at\") #>> '{}' AS \"at\"");
+    assert_eq!(SqliteDialect.select_temporal("\"at\"", "\"at\""), "\"at\" AS \"at\"");
+    assert!(MySqlDialect.upsert_reports_skipped_rows());
+    assert!(!PostgresDialect.upsert_reports_skipped_rows());
+}
+
+#[tokio::test]
+async fn test_custom_dialect_value_encoding() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).dialect(CompactDateDialect).connect("sqlite::memory:").await?;
+    db.migrator().register::<Holiday>().run().await?;
+
+    let day = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    db.model::<Holiday>().insert(&Holiday { id: 1, day }).await?;
+
+    // Values are bound through the dialect...
+    let stored: String = db.raw("SELECT \"day\" FROM \"holiday\"").fetch_scalar().await?;
+    assert_eq!(stored, "20241225");
+
+    // ...and read back through its temporal projection
+    let holiday: Holiday = db.model::<Holiday>().first().await?;
+    assert_eq!(holiday.day, day);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_custom_dialect() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).dialect(BracketDialect).connect("sqlite::memory:").await?;
+    db.migrator().register::<Note>().run().await?;
+
+    db.model::<Note>().insert(&Note { id: 1, body: "a".to_string() }).await?;
+    db.model::<Note>().insert(&Note { id: 2, body: "b".to_string() }).await?;
+
+    let notes: Vec<Note> = db.model::<Note>().order("id DESC").offset(1).scan().await?;
+    assert_eq!(notes, vec![Note { id: 1, body: "a".to_string() }]);
+
+    // A dialect must match the connection's driver family
+    let mismatch = Database::builder().dialect(PostgresDialect).connect("sqlite::memory:").await;
+    assert!(mismatch.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use bottle_orm::{raw, Database, Error, Model};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Author {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Book {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(foreign_key = "Author::id")]
+    author_id: i32,
+    title: String,
+}
+
+#[tokio::test]
+async fn test_strict_mode_rejects_unknown_identifiers() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Author>().register::<Book>().run().await?;
+    db.model::<Author>().insert(&Author { id: 1, name: "Ann".to_string() }).await?;
+    db.model::<Author>().insert(&Author { id: 2, name: "Bob".to_string() }).await?;
+
+    // Valid user-supplied sort is quoted
+    let sql = db.model::<Author>().strict().order("name desc, id").to_sql();
+    assert!(sql.ends_with("ORDER BY \"name\" DESC, \"id\" ASC"));
+    let authors: Vec<Author> = db.model::<Author>().strict().order("name desc").scan().await?;
+    assert_eq!(authors[0].name, "Bob");
+
+    // Injection attempts and unknown columns never reach the database
+    assert!(db.model::<Author>().strict().order("name; DROP TABLE author").scan::<Author>().await.is_err());
+    assert!(db.model::<Author>().strict().order("(SELECT 1)").scan::<Author>().await.is_err());
+    assert!(db.model::<Author>().strict().select("password").scan::<Author>().await.is_err());
+    assert!(db.model::<Author>().strict().group_by("name, 1=1").count().await.is_err());
+    assert!(db.model::<Author>().strict().max::<i32>("id) FROM author; --").await.is_err());
+    assert!(db.model::<Author>().strict().join("book", "author.id = book.author_id OR 1").count().await.is_err());
+    assert_eq!(db.model::<Author>().count().await?, 2);
+
+    // Malformed join conditions are reported instead of panicking
+    let no_equals = db.model::<Author>().strict().join("book", "author.id book.author_id").count().await;
+    assert!(matches!(no_equals, Err(Error::InvalidArgument(_))), "got {:?}", no_equals);
+    let no_table = db.model::<Author>().strict().join("book", "id = book.author_id").count().await;
+    assert!(matches!(no_table, Err(Error::InvalidArgument(_))), "got {:?}", no_table);
+    assert!(matches!(db.model::<Author>().join("book", "id").count().await, Err(Error::InvalidArgument(_))));
+
+    // Without strict mode the string is still passed through
+    let sql = db.model::<Author>().order("length(name) DESC").to_sql();
+    assert!(sql.ends_with("ORDER BY length(name) DESC"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_strict_mode_with_raw_and_joined_models() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Author>().register::<Book>().run().await?;
+    db.model::<Author>().insert(&Author { id: 1, name: "Ann".to_string() }).await?;
+    for (id, title) in [(1, "A"), (2, "B")] {
+        db.model::<Book>().insert(&Book { id, author_id: 1, title: title.to_string() }).await?;
+    }
+
+    // Expressions are allowed through raw()
+    let books: i64 = db
+        .model::<Book>()
+        .strict()
+        .select(raw("COUNT(*)"))
+        .group_by("author_id")
+        .having(raw("COUNT(*)"), ">", 1)
+        .scalar()
+        .await?;
+    assert_eq!(books, 2);
+
+    // Columns of joined models are validated against their metadata
+    let titles: i64 = db
+        .model::<Author>()
+        .strict()
+        .join_model::<Book>("author.id = book.author_id")
+        .order("book.title DESC")
+        .count()
+        .await?;
+    assert_eq!(titles, 2);
+
+    let unknown =
+        db.model::<Author>().strict().join_model::<Book>("author.id = book.author_id").order("book.isbn").count().await;
+    assert!(unknown.is_err());
+
+    Ok(())
+}
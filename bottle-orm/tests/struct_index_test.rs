@@ -0,0 +1,55 @@
+use bottle_orm::{Database, Model};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+#[orm(index(name = "idx_live_posts", columns(author_id, created_at desc), where = "deleted_at IS NULL"))]
+#[orm(index(columns(author_id, slug), unique))]
+struct Post {
+    #[orm(primary_key)]
+    id: i32,
+    author_id: i32,
+    slug: String,
+    created_at: String,
+    deleted_at: Option<String>,
+}
+
+#[test]
+fn test_struct_index_metadata() {
+    let indexes = Post::indexes();
+    assert_eq!(indexes.len(), 2);
+
+    assert_eq!(indexes[0].name, "idx_live_posts");
+    assert_eq!(indexes[0].columns.len(), 2);
+    assert!(!indexes[0].columns[0].descending);
+    assert!(indexes[0].columns[1].descending);
+    assert_eq!(indexes[0].predicate, Some("deleted_at IS NULL"));
+
+    // Unnamed indexes follow the idx_{table}_{columns} convention
+    assert_eq!(indexes[1].name, "idx_post_author_id_slug");
+    assert!(indexes[1].unique);
+    assert_eq!(indexes[1].method, None);
+}
+
+#[tokio::test]
+async fn test_struct_indexes_are_created() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Post>().run().await?;
+
+    let partial: String =
+        db.raw("SELECT sql FROM sqlite_master WHERE type = 'index' AND name = 'idx_live_posts'").fetch_scalar().await?;
+    assert!(partial.contains("(\"author_id\", \"created_at\" DESC)"), "{}", partial);
+    assert!(partial.contains("WHERE deleted_at IS NULL"), "{}", partial);
+
+    // Composite unique index rejects a duplicate (author_id, slug) pair
+    let post = |id: i32, author_id: i32| Post {
+        id,
+        author_id,
+        slug: "hello".to_string(),
+        created_at: "2026-01-01".to_string(),
+        deleted_at: None,
+    };
+    db.model::<Post>().insert(&post(1, 1)).await?;
+    db.model::<Post>().insert(&post(2, 2)).await?;
+    assert!(db.model::<Post>().insert(&post(3, 1)).await.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use std::{
+    panic::AssertUnwindSafe,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+use bottle_orm::{Database, Error, Model, Op, RetryPolicy};
+use futures::FutureExt;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Ledger {
+    #[orm(primary_key)]
+    id: i32,
+    amount: i64,
+}
+
+#[tokio::test]
+async fn test_transaction_commits_and_rolls_back() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Ledger>().run().await?;
+
+    let id = db
+        .transaction(|tx| {
+            Box::pin(async move {
+                tx.model::<Ledger>().insert(&Ledger { id: 1, amount: 10 }).await?;
+                Ok::<_, Error>(1)
+            })
+        })
+        .await?;
+    assert_eq!(id, 1);
+
+    let failed = db
+        .transaction(|tx| {
+            Box::pin(async move {
+                tx.model::<Ledger>().insert(&Ledger { id: 2, amount: 20 }).await?;
+                Err::<(), _>(Error::invalid_data("insufficient funds"))
+            })
+        })
+        .await;
+    assert!(matches!(failed, Err(Error::InvalidData(_))));
+
+    let panicked = AssertUnwindSafe(db.transaction(|tx| {
+        Box::pin(async move {
+            tx.model::<Ledger>().insert(&Ledger { id: 3, amount: 30 }).await?;
+            panic!("handler crashed");
+            #[allow(unreachable_code)]
+            Ok::<(), Error>(())
+        })
+    }))
+    .catch_unwind()
+    .await;
+    assert!(panicked.is_err());
+
+    let ids: Vec<i32> = db.model::<Ledger>().select("id").scan().await?;
+    assert_eq!(ids, vec![1]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_retries_busy_snapshot() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::env::temp_dir().join(format!("bottle_orm_retry_{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let url = format!("sqlite://{}?mode=rwc", path.display());
+
+    let db = Database::builder().max_connections(2).connect(&url).await?;
+    db.raw("PRAGMA journal_mode=WAL").execute().await?;
+    db.migrator().register::<Ledger>().run().await?;
+    db.model::<Ledger>().insert(&Ledger { id: 1, amount: 0 }).await?;
+
+    let attempts = AtomicU32::new(0);
+    let result = db
+        .transaction_with_retry(RetryPolicy::new(3), |tx| {
+            let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+            let db = db.clone();
+            Box::pin(async move {
+                let ledger: Ledger = tx.model::<Ledger>().filter("id", Op::Eq, 1).first().await?;
+                if attempt == 0 {
+                    // A concurrent commit invalidates this transaction's snapshot (SQLITE_BUSY_SNAPSHOT)
+                    db.model::<Ledger>().filter("id", Op::Eq, 1).update("amount", 100).await?;
+                }
+                tx.model::<Ledger>().filter("id", Op::Eq, 1).update("amount", ledger.amount + 1).await?;
+                Ok::<_, Error>(ledger.amount + 1)
+            })
+        })
+        .await;
+
+    drop(db);
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(result?, 101);
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_runs_inside_spawned_task() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Ledger>().run().await?;
+
+    // Closure transactions must be `Send` to run on a multi-threaded runtime
+    let task_db = db.clone();
+    let handle = tokio::spawn(async move {
+        task_db
+            .transaction(|tx| {
+                Box::pin(async move {
+                    tx.model::<Ledger>().insert(&Ledger { id: 1, amount: 10 }).await?;
+                    tx.transaction(|sp| {
+                        Box::pin(async move {
+                            sp.model::<Ledger>().insert(&Ledger { id: 2, amount: 20 }).await?;
+                            Ok::<_, Error>(())
+                        })
+                    })
+                    .await?;
+                    Ok::<_, Error>(2)
+                })
+            })
+            .await
+    });
+    assert_eq!(handle.await??, 2);
+
+    assert_eq!(db.model::<Ledger>().count().await?, 2);
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use bottle_orm::{
+    Database, Error, IsolationLevel, Model, TxOptions,
+    dialect::{MySqlDialect, PostgresDialect, SqlDialect, SqliteDialect},
+};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Report {
+    #[orm(primary_key)]
+    id: i32,
+    total: i64,
+}
+
+#[tokio::test]
+async fn test_begin_with_options() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Report>().run().await?;
+
+    let mut tx =
+        db.begin_with(TxOptions { isolation: Some(IsolationLevel::Serializable), ..Default::default() }).await?;
+    tx.model::<Report>().insert(&Report { id: 1, total: 42 }).await?;
+    tx.commit().await?;
+
+    let mut tx = db.begin_with(TxOptions::default()).await?;
+    tx.model::<Report>().insert(&Report { id: 2, total: 7 }).await?;
+    tx.rollback().await?;
+
+    assert_eq!(db.model::<Report>().count().await?, 1);
+
+    let read_only = db.begin_with(TxOptions { read_only: true, ..Default::default() }).await;
+    assert!(matches!(read_only, Err(Error::InvalidArgument(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_begin_statements_per_dialect() {
+    let report = TxOptions { isolation: Some(IsolationLevel::Serializable), read_only: true, deferrable: true };
+
+    assert_eq!(PostgresDialect.begin_statements(&TxOptions::default()), vec!["BEGIN"]);
+    assert_eq!(
+        PostgresDialect.begin_statements(&report),
+        vec!["BEGIN ISOLATION LEVEL SERIALIZABLE, READ ONLY, DEFERRABLE"]
+    );
+
+    assert_eq!(
+        MySqlDialect.begin_statements(&report),
+        vec!["SET TRANSACTION ISOLATION LEVEL SERIALIZABLE", "START TRANSACTION READ ONLY"]
+    );
+    assert_eq!(MySqlDialect.begin_statements(&TxOptions::default()), vec!["START TRANSACTION"]);
+
+    assert_eq!(SqliteDialect.begin_statements(&report), vec!["BEGIN"]);
+    assert!(PostgresDialect.supports_read_only_transactions());
+    assert!(MySqlDialect.supports_read_only_transactions());
+    assert!(!SqliteDialect.supports_read_only_transactions());
+}
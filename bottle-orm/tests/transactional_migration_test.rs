@@ -0,0 +1,74 @@
+use bottle_orm::{
+    Database, Error, Model, SqlMigration, SqlScript,
+    dialect::{MySqlDialect, PostgresDialect, SqlDialect, SqliteDialect},
+};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Author {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Book {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(foreign_key = "Author::id")]
+    author_id: i32,
+    title: String,
+}
+
+async fn table_exists(db: &Database, table: &str) -> Result<bool, Error> {
+    let count: i64 = db
+        .raw("SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = ?")
+        .bind(table)
+        .fetch_scalar()
+        .await?;
+    Ok(count > 0)
+}
+
+#[tokio::test]
+async fn test_schema_changes_inside_transaction() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+
+    let mut tx = db.begin().await?;
+    tx.create_table::<Author>().await?;
+    tx.model::<Author>().insert(&Author { id: 1, name: "Ursula".to_string() }).await?;
+    tx.rollback().await?;
+    assert!(!table_exists(&db, "author").await?);
+
+    let mut tx = db.begin().await?;
+    tx.migrator().register::<Author>().register::<Book>().run().await?;
+    tx.model::<Author>().insert(&Author { id: 1, name: "Ursula".to_string() }).await?;
+    tx.model::<Book>().insert(&Book { id: 1, author_id: 1, title: "The Dispossessed".to_string() }).await?;
+    tx.commit().await?;
+
+    assert_eq!(db.model::<Book>().count().await?, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transactional_migrator_rolls_back_on_failure() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+
+    let broken = SqlMigration {
+        version: 1,
+        name: "broken",
+        up: SqlScript { default: Some("INSERT INTO \"missing\" VALUES (1)"), ..Default::default() },
+        down: SqlScript::default(),
+    };
+    let result = db.migrator().transactional().register::<Author>().register_sql(&[broken]).run().await;
+    assert!(result.is_err());
+    assert!(!table_exists(&db, "author").await?);
+
+    db.migrator().transactional().register::<Author>().register::<Book>().run().await?;
+    assert!(table_exists(&db, "book").await?);
+
+    assert!(PostgresDialect.supports_transactional_ddl());
+    assert!(SqliteDialect.supports_transactional_ddl());
+    assert!(!MySqlDialect.supports_transactional_ddl());
+
+    Ok(())
+}
@@ -47,7 +47,7 @@ async fn test_tuple_join() -> Result<(), Box<dyn std::error::Error>> {
         created_at: now,
     };
 
-    let account = Account { id: account_id, user_id: user_id, account_type: "credential".to_string(), balance: 100.50 };
+    let account = Account { id: account_id, user_id, account_type: "credential".to_string(), balance: 100.50 };
 
     db.model::<User>().insert(&user).await?;
     db.model::<Account>().insert(&account).await?;
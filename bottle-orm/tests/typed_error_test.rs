@@ -0,0 +1,74 @@
+use bottle_orm::{Database, Error, Model, Op, raw};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Member {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(unique)]
+    email: String,
+    #[orm(check = "age >= 0")]
+    age: i32,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Badge {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(foreign_key = "Member::id")]
+    member_id: i32,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Member>().register::<Badge>().run().await?;
+    db.model::<Member>().insert(&Member { id: 1, email: "ada@example.com".to_string(), age: 36 }).await?;
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_constraint_violations_are_classified() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let duplicate = Member { id: 2, email: "ada@example.com".to_string(), age: 20 };
+    match db.model::<Member>().insert(&duplicate).await {
+        Err(Error::UniqueViolation { columns, .. }) => assert_eq!(columns, vec!["email".to_string()]),
+        other => panic!("expected unique violation, got {:?}", other),
+    }
+
+    let negative = Member { id: 3, email: "grace@example.com".to_string(), age: -1 };
+    let result = db.model::<Member>().insert(&negative).await;
+    assert!(matches!(result, Err(Error::CheckViolation { .. })), "got {:?}", result);
+
+    let orphan = Badge { id: 1, member_id: 42 };
+    let result = db.model::<Badge>().insert(&orphan).await;
+    assert!(matches!(result, Err(Error::ForeignKeyViolation { .. })), "got {:?}", result);
+
+    match db.raw("INSERT INTO \"member\" (\"id\", \"email\", \"age\") VALUES (4, NULL, 1)").execute().await {
+        Err(Error::NotNullViolation { column, .. }) => assert_eq!(column.as_deref(), Some("email")),
+        other => panic!("expected not null violation, got {:?}", other),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_not_found_and_invalid_arguments() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let missing = db.model::<Member>().filter("id", Op::Eq, 99).first::<Member>().await;
+    assert!(matches!(missing, Err(Error::NotFound)));
+
+    let missing = db.raw("SELECT * FROM \"member\" WHERE \"id\" = 99").fetch_one::<Member>().await;
+    assert!(matches!(missing, Err(Error::NotFound)));
+
+    // Builder errors are reported as they are, no longer wrapped in `sqlx::Error::Configuration`
+    let invalid = db.model::<Member>().select_expr(raw("1"), "not an alias").scan::<Member>().await;
+    assert!(matches!(invalid, Err(Error::InvalidArgument(_))));
+
+    // The driver error stays reachable through `source()`
+    let error = db.model::<Member>().insert(&Member { id: 1, email: "x".to_string(), age: 1 }).await.unwrap_err();
+    let source = std::error::Error::source(&error).and_then(|e| e.downcast_ref::<sqlx::Error>());
+    assert!(matches!(source, Some(sqlx::Error::Database(_))));
+
+    Ok(())
+}
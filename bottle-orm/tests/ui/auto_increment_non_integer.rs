@@ -0,0 +1,9 @@
+use bottle_orm::Model;
+
+#[derive(Debug, Clone, Model)]
+struct Token {
+    #[orm(primary_key, auto_increment)]
+    id: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bottle_orm::Model;
+
+#[derive(Debug, Clone, Model)]
+struct Invoice {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(stored)]
+    total: i64,
+}
+
+fn main() {}
@@ -0,0 +1,96 @@
+use bottle_orm::{Database, Direction, FrameBound, FromAnyRow, Model, Window};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Purchase {
+    #[orm(primary_key)]
+    id: i32,
+    user_id: i32,
+    amount: i32,
+}
+
+#[derive(Debug, FromAnyRow)]
+struct RankedPurchase {
+    id: i32,
+    user_id: i32,
+    position: i64,
+    running_total: i64,
+}
+
+#[derive(Debug, FromAnyRow)]
+struct UserRank {
+    id: i32,
+    user_rank: i64,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Purchase>().run().await?;
+
+    for (id, user_id, amount) in [(1, 1, 10), (2, 2, 5), (3, 1, 20), (4, 1, 30), (5, 2, 15)] {
+        db.model::<Purchase>().insert(&Purchase { id, user_id, amount }).await?;
+    }
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_window_functions_decode_into_struct() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let rows: Vec<RankedPurchase> = db
+        .model::<Purchase>()
+        .select("id")
+        .select("user_id")
+        .select_expr(Window::row_number().partition_by("user_id").order_by("id", Direction::Asc), "position")
+        .select_expr(
+            Window::sum("amount")
+                .partition_by("user_id")
+                .order_by("id", Direction::Asc)
+                .rows_between(FrameBound::UnboundedPreceding, FrameBound::CurrentRow),
+            "running_total",
+        )
+        .order("id")
+        .scan()
+        .await?;
+
+    let summary: Vec<(i32, i32, i64, i64)> =
+        rows.iter().map(|r| (r.id, r.user_id, r.position, r.running_total)).collect();
+    assert_eq!(summary, vec![(1, 1, 1, 10), (2, 2, 1, 5), (3, 1, 2, 30), (4, 1, 3, 60), (5, 2, 2, 20)]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_window_expression_sql_and_validation() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let sql = db
+        .model::<Purchase>()
+        .select("id")
+        .select_expr(Window::lag("amount", 1).order_by("id", Direction::Asc), "previous")
+        .to_sql();
+    assert!(sql.starts_with("SELECT id, LAG(\"amount\", 1) OVER (ORDER BY \"id\" ASC) AS \"previous\" FROM"));
+
+    let ranks: Vec<UserRank> = db
+        .model::<Purchase>()
+        .select("id")
+        .select_expr(Window::dense_rank().order_by("user_id", Direction::Asc), "user_rank")
+        .order("id")
+        .scan()
+        .await?;
+    assert_eq!(
+        ranks.iter().map(|r| (r.id, r.user_rank)).collect::<Vec<_>>(),
+        vec![(1, 1), (2, 2), (3, 1), (4, 1), (5, 2)]
+    );
+
+    // Strict mode rejects unknown columns inside the window definition
+    let invalid = db
+        .model::<Purchase>()
+        .strict()
+        .select("id")
+        .select_expr(Window::rank().partition_by("missing"), "rank")
+        .scan::<UserRank>()
+        .await;
+    assert!(invalid.is_err());
+
+    Ok(())
+}